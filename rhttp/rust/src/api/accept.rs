@@ -0,0 +1,18 @@
+use crate::utils::accept;
+use flutter_rust_bridge::frb;
+
+/// Builds a canonical `Accept` header value from `(media_type, q)` pairs,
+/// e.g. `[("application/json", 1.0), ("text/xml", 0.5)]` ->
+/// `"application/json, text/xml;q=0.5"`. The first pair is treated as the
+/// caller's most-preferred type and omits its q-value when it's `1.0`.
+/// Returns an error message if the list is empty, a media type is missing
+/// its `/`, or a q-value isn't in `0.0..=1.0`. Pass the result as a header
+/// via `HttpHeaders`.
+#[frb(sync)]
+pub fn build_accept_header(media_types: Vec<(String, f32)>) -> Result<String, String> {
+    let entries: Vec<accept::AcceptEntry> = media_types
+        .into_iter()
+        .map(|(media_type, q)| accept::AcceptEntry { media_type, q })
+        .collect();
+    accept::build_header(&entries)
+}
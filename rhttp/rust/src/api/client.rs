@@ -1,19 +1,38 @@
 use crate::api::error::RhttpError;
-use crate::api::http::HttpVersionPref;
+use crate::api::http::{HttpVersion, HttpVersionPref};
+use crate::utils::access_control;
+use crate::utils::har::HarEntry;
+use crate::utils::rate_limiter::TokenBucket;
 use crate::utils::socket_addr::SocketAddrDigester;
 use chrono::Duration;
 use flutter_rust_bridge::{frb, DartFnFuture};
+use futures_util::StreamExt;
 use reqwest::dns::{Addrs, Name, Resolve, Resolving};
 use reqwest::{tls, Certificate};
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Mutex as AsyncMutex;
 pub use tokio_util::sync::CancellationToken;
 
+/// Applied when `ClientSettings::max_response_header_bytes` is unset.
+const DEFAULT_MAX_RESPONSE_HEADER_BYTES: u32 = 64 * 1024;
+
+/// reqwest's own default redirect limit, used to build a scheme-checking
+/// policy when `ClientSettings::require_https` is set but `redirect_settings`
+/// isn't -- otherwise there'd be no policy to hook the check into.
+const DEFAULT_REDIRECT_LIMIT: i32 = 10;
+
+#[derive(Clone)]
 pub struct ClientSettings {
     pub cookie_settings: Option<CookieSettings>,
     pub http_version_pref: HttpVersionPref,
+
+    /// Only takes effect when `http_version_pref` is `HttpVersionPref::Http3`
+    /// -- see `Http3Settings`.
+    pub http3_settings: Option<Http3Settings>,
+
     pub timeout_settings: Option<TimeoutSettings>,
     pub throw_on_status_code: bool,
     pub proxy_settings: Option<ProxySettings>,
@@ -21,40 +40,650 @@ pub struct ClientSettings {
     pub tls_settings: Option<TlsSettings>,
     pub dns_settings: Option<DnsSettings>,
     pub user_agent: Option<String>,
+
+    /// Opt into an HTTP cache honoring RFC 7234 `Cache-Control` freshness
+    /// and ETag/Last-Modified revalidation.
+    ///
+    /// Not yet wired: reqwest has no built-in cache layer, and adding one
+    /// properly (freshness calculation, `Vary` handling, conditional
+    /// revalidation, hit/miss/revalidated status on the response) is a
+    /// bigger change than a `ClientBuilder` option can express. The field
+    /// is accepted so callers can start opting in, but `create_client`
+    /// currently ignores it and every request behaves as if it were unset.
+    pub cache_settings: Option<CacheSettings>,
+
+    /// Aborts a response whose headers exceed this many bytes with
+    /// `RhttpError::RhttpHeadersTooLarge`, guarding against a server that
+    /// sends megabytes of headers before the body is even read.
+    ///
+    /// Only enforced on HTTP/2, via `http2_max_header_list_size` -- reqwest
+    /// doesn't expose an equivalent limit for HTTP/1.1 response headers.
+    /// `None` uses `DEFAULT_MAX_RESPONSE_HEADER_BYTES` rather than being
+    /// unbounded; this is a deliberate behavior change from plain reqwest.
+    pub max_response_header_bytes: Option<u32>,
+
+    /// Connect over a unix domain socket at this path instead of TCP, using
+    /// the scheme/host/path from the request URL only for the HTTP `Host`
+    /// header and request line (e.g. talking to a local daemon like
+    /// Docker's API).
+    ///
+    /// Not yet wired: reqwest's public `ClientBuilder` doesn't expose a way
+    /// to swap in a custom transport/connector in this version, so setting
+    /// this currently always returns `RhttpError::RhttpUnsupportedError`,
+    /// on every platform including unix ones. The field is accepted so the
+    /// API shape is ready once reqwest exposes (or we vendor) a connector
+    /// hook.
+    pub unix_socket_path: Option<String>,
+
+    /// Invoked for each informational (1xx) response received before the
+    /// final status, e.g. to act on `103 Early Hints` preload links.
+    ///
+    /// Not yet wired: reqwest (via hyper) doesn't surface interim 1xx
+    /// responses through its public API at all -- they're consumed
+    /// internally while hyper waits for the final response. Observing
+    /// them would require a hook hyper doesn't expose, so this callback
+    /// is accepted but never invoked.
+    pub on_informational:
+        Option<Arc<dyn Fn(u16, Vec<(String, String)>) -> DartFnFuture<()> + Send + Sync>>,
+
+    /// Aborts response decoding with `RhttpError::RhttpDecompressionBomb`
+    /// once decompressed bytes exceed `input bytes * ratio`, guarding
+    /// against a small compressed body inflating to gigabytes.
+    ///
+    /// Only enforced on `make_http_request_receive_ndjson`'s
+    /// `decompress_gzip_stream` path, which decodes gzip manually chunk by
+    /// chunk and so can observe the running input/output byte counts. Every
+    /// other response is still decompressed by reqwest internally (via
+    /// `async-compression`, driven from inside its response body stream),
+    /// which doesn't expose those counts and only offers an all-or-nothing
+    /// `.gzip(bool)`-style toggle -- decoding there remains unbounded
+    /// regardless of this setting.
+    pub max_decompression_ratio: Option<f64>,
+
+    /// Restricts automatic response decompression to responses whose
+    /// `Content-Type` matches one of these rules, e.g. decompressing
+    /// `application/json` while passing an `application/octet-stream`
+    /// download through with its `Content-Encoding` left untouched. An
+    /// empty list keeps the default all-or-nothing behavior from the
+    /// per-codec `gzip`/`brotli`/`deflate` Cargo features.
+    ///
+    /// Not yet wired: reqwest decompresses a response's body internally,
+    /// driven off `Content-Encoding` alone, before the body stream (and
+    /// therefore anything reading `Content-Type` from the response
+    /// headers) is ever handed back to us -- the same internal-decoder gap
+    /// `max_decompression_ratio` runs into. The field is accepted so the
+    /// API shape is ready, but every response is still decompressed (or
+    /// not) purely based on the enabled codec features, regardless of
+    /// these rules.
+    pub decompression_content_type_rules: Vec<DecompressionRule>,
+
+    /// Invoked when a pooled connection is created, reused for a new
+    /// request, or evicted/closed, with the target host and an opaque id
+    /// identifying the connection.
+    ///
+    /// Not yet wired: reqwest's connector and connection pool are
+    /// internal to `hyper-util`, with no public hook to observe pool
+    /// lifecycle events -- `PoolConfig`-style tuning like
+    /// `pool_max_idle_per_host` is settable, but its effects aren't
+    /// observable. The field is accepted so the API shape is ready, but
+    /// no event is ever emitted.
+    pub on_pool_event: Option<Arc<dyn Fn(PoolEvent) -> DartFnFuture<()> + Send + Sync>>,
+
+    /// Caps how many sockets this client may have open at once, across all
+    /// hosts combined -- distinct from `pool_max_idle_per_host`-style
+    /// per-host limits, and from any request-concurrency cap, since it
+    /// counts connections rather than in-flight requests. Meant for a
+    /// device with a tight system-wide file descriptor budget. `None`
+    /// applies no cap.
+    ///
+    /// Not yet wired: enforcing this needs a semaphore acquired inside the
+    /// connector before a new socket is opened (and released when it
+    /// closes), and reqwest's connector is internal to `hyper-util` with no
+    /// hook to gate connection establishment in this version -- the same
+    /// gap `on_pool_event` runs into. The field is accepted so the API
+    /// shape is ready, but every client currently opens as many
+    /// connections as it would with this unset.
+    pub max_total_connections: Option<usize>,
+
+    /// Caps how many requests to the same host may be in flight at once,
+    /// while allowing unlimited concurrency across different hosts --
+    /// e.g. an API that rate-limits per endpoint. `None` applies no cap.
+    ///
+    /// Unlike `max_total_connections`, this is enforced entirely inside
+    /// this library, by acquiring a permit from a per-host semaphore
+    /// before a request is sent and releasing it once the request
+    /// completes (or is cancelled) -- it counts logical requests, not
+    /// open sockets, so a request queued on this cap doesn't necessarily
+    /// mean a connection is open for it yet.
+    pub max_concurrent_per_host: Option<usize>,
+
+    /// Caps the total request + response body bytes this client (and every
+    /// clone sharing its state) may transfer before further requests are
+    /// refused with `RhttpError::RhttpQuotaExceeded`, e.g. to hard-stop on
+    /// a metered connection. `None` applies no cap. A request already in
+    /// flight when the quota is crossed is allowed to finish; only the
+    /// next request is refused, checked once up front before it's sent.
+    ///
+    /// Only counts a body once its size is known: buffered
+    /// (`HttpBody::Text`/`Bytes`) request bodies and every response body,
+    /// buffered or streamed. A streamed (`HttpBody::BytesStream`) upload
+    /// isn't counted, since its total size is never known to this library
+    /// -- see `RequestClient::add_bytes_transferred`.
+    pub byte_quota: Option<u64>,
+
+    /// Opt into failing a request instantly with `RhttpError::RhttpOffline`
+    /// when the network is unusable, instead of waiting out a full connect
+    /// timeout -- useful on mobile, where "no network" is common and slow
+    /// to discover otherwise. `None` disables the check (the default);
+    /// `Some(timeout)` runs a fast TCP connect probe against the request's
+    /// host bounded by `timeout` before every request.
+    ///
+    /// There's no platform network-status API available to this library,
+    /// so the probe is a real (if short) connection attempt on every
+    /// platform rather than a free OS-level check; see `check_reachable`
+    /// in `http.rs`.
+    pub offline_detection: Option<Duration>,
+
+    /// Called when a request gets a 401, to obtain a fresh `Authorization`
+    /// header value and retry once. Returns `None` to give up, in which
+    /// case the original 401 is returned unchanged.
+    ///
+    /// A burst of 401s across concurrent requests on this client shares one
+    /// in-flight refresh rather than calling this once per request -- see
+    /// `RequestClient::refresh_authorization`.
+    pub on_unauthorized: Option<Arc<dyn Fn() -> DartFnFuture<Option<String>> + Send + Sync>>,
+
+    /// Caps upload/download throughput, e.g. to test app behavior on slow
+    /// networks or to be polite on metered connections. `None` (the
+    /// default) applies no limit in either direction.
+    pub bandwidth_settings: Option<BandwidthSettings>,
+
+    /// Called after every other request mutation (client/default headers,
+    /// `remove_headers`) but before the request is sent, so a caller can add
+    /// signature headers (e.g. AWS SigV4's `Authorization` and
+    /// `x-amz-content-sha256`) that are guaranteed to cover exactly what
+    /// goes on the wire. Returned headers are merged into the request,
+    /// overwriting any existing value with the same name.
+    pub on_sign: Option<Arc<dyn Fn(SignRequest) -> DartFnFuture<Vec<(String, String)>> + Send + Sync>>,
+
+    /// Generates a fresh 8-byte span id (16 lowercase hex characters) for
+    /// each request carrying a `TraceContext`, used to build that
+    /// request's `traceparent` header. A request with a `TraceContext` but
+    /// no hook configured here sends no tracing headers at all, rather
+    /// than one with a made-up span id.
+    pub on_generate_span_id: Option<Arc<dyn Fn() -> DartFnFuture<String> + Send + Sync>>,
+
+    /// Rejects any request whose URL isn't `https://` with
+    /// `RhttpError::RhttpInsecureScheme`, before connecting -- including a
+    /// redirect that would downgrade to `http://`. Off by default so
+    /// existing callers aren't broken by a new safety check.
+    pub require_https: bool,
+
+    /// A request-smuggling hardening check: rejects a response with
+    /// `RhttpError::RhttpProtocolError` if it carries more than one
+    /// `Content-Length` header with disagreeing values, or both
+    /// `Content-Length` and `Transfer-Encoding` -- both are classic signs
+    /// that a front-end proxy and this client could disagree on where the
+    /// body ends. Checked once, against the final response's headers. Off
+    /// by default so a tolerant intermediary already in production doesn't
+    /// suddenly start failing requests; recommended for anything proxied.
+    /// See `utils::smuggling::check_for_smuggling_signature`.
+    pub reject_ambiguous_content_length: bool,
+
+    /// Opt into capturing the raw bytes of the request and response at the
+    /// HTTP layer (request line + headers + body, and the same for the
+    /// response), exposed on `HttpResponse::raw_request`/`raw_response` for
+    /// protocol-level debugging.
+    ///
+    /// Not yet wired: capturing the exact wire bytes needs a tee inserted
+    /// at the connector, before/after TLS, and reqwest's public
+    /// `ClientBuilder` doesn't expose a hook at that layer in this version
+    /// (the same gap `unix_socket_path` runs into). The field is accepted
+    /// so the API shape is ready, but `HttpResponse::raw_request`/
+    /// `raw_response` are always `None` regardless of this setting.
+    pub raw_capture: Option<RawCaptureSettings>,
+
+    /// An SSRF guard: rejects a request with `RhttpError::RhttpBlockedAddress`
+    /// if none of its hostname's resolved IPs pass the rules. `None` (the
+    /// default) performs no check.
+    ///
+    /// The check runs on the *resolved* address, not the hostname, and runs
+    /// again on every redirect hop (each hop re-resolves its own host), so
+    /// it also catches a hostname that DNS-rebinds to a blocked address
+    /// between the check and the connection attempt.
+    ///
+    /// Doesn't see addresses supplied via `DnsSettings::StaticDns`'s
+    /// per-host `overrides`: those are handed to reqwest through a
+    /// mechanism (`resolve_to_addrs`) that bypasses the resolver hook this
+    /// check is built on. `DnsSettings::DynamicDns` is checked normally,
+    /// since it *is* a resolver hook.
+    pub access_control: Option<AccessControl>,
+
+    /// Workaround for a server that corrupts data when multiple h2 streams
+    /// share a connection: caps how many requests this client will run
+    /// concurrently over a single HTTP/2 connection, opening additional
+    /// connections instead of multiplexing past the cap. `None` applies no
+    /// cap (reqwest's normal behavior).
+    ///
+    /// Not yet wired: how many streams a connection may multiplex is set by
+    /// `SETTINGS_MAX_CONCURRENT_STREAMS`, which in HTTP/2 constrains streams
+    /// the *sender* accepts from its peer -- so the server's value governs
+    /// how many streams *this client* may open, and there's no client-side
+    /// knob (in reqwest's public API or in HTTP/2 itself) that limits how
+    /// many streams the client itself opens on one connection. The field is
+    /// accepted so the API shape is ready if reqwest grows a pool-level
+    /// per-connection concurrency cap, but every request currently behaves
+    /// as if it were unset.
+    pub http2_max_concurrent_streams_per_conn: Option<u32>,
+
+    /// Opt into `HttpResponse::debug_info`, a summary of what was actually
+    /// applied to a request after all per-request overrides -- negotiated
+    /// version, proxy used, timeout applied, and whether the 401-refresh
+    /// retry fired. Off by default since assembling it costs a little extra
+    /// work on every request for something most callers never read.
+    pub capture_debug_info: bool,
+
+    /// Retries the connection phase (DNS + TCP/TLS connect) up to this many
+    /// times, with a short backoff between attempts, before giving up.
+    /// Distinct from -- and independent of -- request-level retries
+    /// (`on_unauthorized`'s 401 refresh, redirects): a connect failure
+    /// means no bytes of the request were ever sent, so retrying it is
+    /// always safe, even for a non-idempotent method. `0` (the default)
+    /// retries no connect failures.
+    ///
+    /// Each attempt is still bounded by `TimeoutSettings::connect_timeout`
+    /// as usual; this only controls how many times a failed attempt is
+    /// retried, not how long any single attempt may take.
+    pub connect_retries: u32,
+
+    /// A `HttpBody::Text`/`Bytes` body at or under this size is kept around
+    /// so it can be resent verbatim by `connect_retries`, `on_unauthorized`'s
+    /// 401 refresh, and `RedirectSettings::LimitedRedirectsPreserveMethod`,
+    /// even when it was otherwise sent as a stream (chunked encoding,
+    /// bandwidth throttling) and so couldn't be cloned off the in-flight
+    /// request. `None` uses a 64 KiB default; buffering a body this small
+    /// again costs effectively nothing. A body over the threshold, or an
+    /// explicitly streamed `HttpBody::BytesStream`, still has to opt out of
+    /// these retries as before.
+    pub body_replay_threshold_bytes: Option<u64>,
+
+    /// Speak HTTP over a socket the caller already connected (e.g. a
+    /// platform networking stack that hands this library a pre-connected
+    /// file descriptor), instead of dialing the request's host itself.
+    /// Given as a raw unix file descriptor; ownership passes to this
+    /// client for the duration of the request the descriptor is used for
+    /// -- it must not be read from, written to, or closed by the caller
+    /// afterward.
+    ///
+    /// Not yet wired: reqwest's public `ClientBuilder` doesn't expose a
+    /// way to swap in a transport for a single request (the same gap
+    /// `unix_socket_path` runs into), so setting this currently always
+    /// fails client creation with `RhttpError::RhttpUnsupportedError`
+    /// rather than silently ignoring the fd and dialing normally.
+    pub external_socket_fd: Option<i32>,
+
+    /// Bind this client's DNS resolution and socket connections to a
+    /// specific Android `Network` (e.g. a VPN or cellular network obtained
+    /// from `ConnectivityManager`), instead of the device's default
+    /// network. Given as the `long` returned by `Network.getNetworkHandle()`
+    /// -- the caller must keep the underlying `Network` alive for as long
+    /// as any client built with this setting is still in use; once the
+    /// `Network` is torn down by the OS, the handle becomes invalid and
+    /// in-flight requests using it will fail.
+    ///
+    /// Not yet wired, and Android-only: reqwest's public `ClientBuilder`
+    /// doesn't expose a way to route an individual connection through
+    /// Android's per-socket `android_setsocknetwork`/`Network.bindSocket`
+    /// APIs, so setting this always fails client creation with
+    /// `RhttpError::RhttpUnsupportedError` -- on Android because the hook
+    /// doesn't exist yet, and on every other platform because the concept
+    /// doesn't apply there at all.
+    pub android_network_handle: Option<i64>,
+
+    /// Low-level TCP tuning for new connections. `None` uses reqwest's own
+    /// defaults.
+    pub tcp_settings: Option<TcpSettings>,
+
+    /// Invoked the moment a new connection to a host is fully established
+    /// (post-TLS) but before any request is sent over it, carrying the
+    /// negotiated protocol version and TLS details -- useful for a UI that
+    /// wants to show "connecting… connected… downloading…" states. Not
+    /// called again when an existing connection is reused for a later
+    /// request; see `on_pool_event` for that.
+    ///
+    /// Not yet wired: like `on_pool_event`, this needs a hook inside
+    /// reqwest's connector, which is internal to `hyper-util` and doesn't
+    /// expose one in this version. The field is accepted so the API shape
+    /// is ready, but no callback is ever invoked.
+    pub on_connection_established:
+        Option<Arc<dyn Fn(ConnectionEstablishedEvent) -> DartFnFuture<()> + Send + Sync>>,
+
+    /// Automatically sets the `Referer` header on a redirected request to
+    /// the previous request's URL, with credentials and fragment stripped
+    /// -- and never set at all when the redirect downgrades from `https` to
+    /// `http`, per the standard referrer policy. Defaults to `true`,
+    /// matching reqwest's own default.
+    ///
+    /// Applies to both reqwest's own redirect following and
+    /// `RedirectSettings::LimitedRedirectsPreserveMethod`'s hand-rolled
+    /// retry loop, via `utils::referer::header_for_redirect`.
+    pub referer: bool,
+}
+
+/// See `ClientSettings::access_control`.
+#[derive(Clone)]
+pub struct AccessControl {
+    /// Addresses or CIDR ranges (e.g. `"10.0.0.5"`, `"10.0.0.0/8"`) that are
+    /// always allowed, overriding both `deny` and `block_private_ranges`.
+    pub allow: Vec<String>,
+
+    /// Addresses or CIDR ranges that are always blocked.
+    pub deny: Vec<String>,
+
+    /// Blocks loopback, link-local (including the `169.254.169.254`
+    /// cloud-metadata address), and RFC 1918/unique-local private ranges,
+    /// unless the address also matches `allow`.
+    pub block_private_ranges: bool,
+}
+
+/// The finalized request handed to `ClientSettings::on_sign`.
+pub struct SignRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+
+    /// The raw body bytes, if the body is buffered in memory; empty for a
+    /// streaming body (`HttpBody::BytesStream`) or no body at all. Left
+    /// unhashed -- different signing schemes hash the body differently
+    /// (SigV4 wants SHA-256 of the raw bytes, for instance), so hashing is
+    /// left to the signer.
+    pub body: Vec<u8>,
+}
+
+/// See `ClientSettings::raw_capture`.
+#[derive(Clone, Copy)]
+pub struct RawCaptureSettings {
+    /// Caps how many bytes of each of `raw_request`/`raw_response` are
+    /// kept, so capturing a large body doesn't balloon memory use.
+    pub max_bytes: u32,
+}
+
+/// See `ClientSettings::bandwidth_settings`.
+#[derive(Clone, Copy, Default)]
+pub struct BandwidthSettings {
+    /// Caps how fast a response body is read, smoothed via a token bucket
+    /// rather than allowing bursts followed by stalls -- see
+    /// `utils::rate_limiter::TokenBucket`. `None` applies no limit.
+    pub download_bps: Option<u64>,
+
+    /// Caps how fast a request body is sent, smoothed the same way as
+    /// `download_bps`. Only applies to `HttpBody::Text`, `HttpBody::Bytes`,
+    /// and `HttpBody::BytesStream` bodies -- `Form` and `Multipart` bodies
+    /// are built and streamed internally by reqwest, which doesn't expose
+    /// a hook to throttle them. `None` applies no limit.
+    pub upload_bps: Option<u64>,
+}
+
+/// A per-request weighting of a client's shared `BandwidthSettings` cap,
+/// for when more than one request is drawing on it concurrently. All of a
+/// client's requests share the same underlying token bucket (see
+/// `RequestClient::download_bucket`/`upload_bucket`), so a `High`-priority
+/// request gets a proportionally larger slice of it than a `Low`-priority
+/// one running at the same time -- neither is limited to a fixed rate of
+/// its own. Requests without an explicit priority are treated as `Normal`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BandwidthPriority {
+    Low,
+    Normal,
+    High,
+}
+
+impl BandwidthPriority {
+    /// This priority's share of the shared token bucket per byte
+    /// transferred, relative to `Normal`'s share of `1.0`. See
+    /// `utils::rate_limiter::TokenBucket::consume_weighted`.
+    pub(crate) fn weight(self) -> f64 {
+        match self {
+            BandwidthPriority::Low => 0.5,
+            BandwidthPriority::Normal => 1.0,
+            BandwidthPriority::High => 2.0,
+        }
+    }
+}
+
+/// A connection pool lifecycle event. See `ClientSettings::on_pool_event`.
+pub struct PoolEvent {
+    pub kind: PoolEventKind,
+    pub host: String,
+    pub connection_id: u64,
+}
+
+#[derive(Clone, Copy)]
+pub enum PoolEventKind {
+    Created,
+    Reused,
+    Closed,
+}
+
+/// A newly-established connection. See
+/// `ClientSettings::on_connection_established`.
+pub struct ConnectionEstablishedEvent {
+    pub host: String,
+    pub negotiated_version: HttpVersion,
+    pub tls_version: Option<String>,
+    pub alpn_protocol: Option<String>,
+}
+
+/// One entry in `ClientSettings::decompression_content_type_rules`: whether
+/// to automatically decompress a response whose `Content-Type` matches
+/// `content_type` exactly (no wildcard/prefix matching).
+#[derive(Clone)]
+pub struct DecompressionRule {
+    pub content_type: String,
+    pub decompress: bool,
 }
 
+#[derive(Clone)]
 pub struct CookieSettings {
     pub store_cookies: bool,
+
+    /// Caps how many cookies are kept for a single domain; once exceeded,
+    /// the least-recently-set cookie for that domain is evicted to make
+    /// room, per RFC 6265's guidance on limiting cookies per domain.
+    /// `None` (the default) means unlimited, matching reqwest's own
+    /// built-in cookie store.
+    pub max_cookies_per_domain: Option<usize>,
+
+    /// Caps how many cookies are kept across all domains combined; once
+    /// exceeded, the least-recently-set cookie overall is evicted. `None`
+    /// (the default) means unlimited.
+    pub max_total_cookies: Option<usize>,
+
+    /// Caps a single cookie's combined name+value size in bytes; a
+    /// `Set-Cookie` header exceeding this is rejected outright rather than
+    /// evicting something else to make room, mirroring how browsers treat
+    /// oversized cookies. `None` (the default) means unlimited.
+    pub max_cookie_size_bytes: Option<usize>,
 }
 
+#[derive(Clone)]
+pub struct CacheSettings {
+    /// Cache responses in memory, evicting the least-recently-used entry
+    /// once `max_entries` is exceeded.
+    pub max_entries: usize,
+
+    /// Also persist cache entries to this directory so they survive
+    /// process restarts.
+    pub disk_cache_dir: Option<String>,
+}
+
+#[derive(Clone)]
 pub enum ProxySettings {
     NoProxy,
     CustomProxyList(Vec<CustomProxy>),
 }
 
+#[derive(Clone)]
 pub struct CustomProxy {
     pub url: String,
     pub condition: ProxyCondition,
+
+    /// Pool tuning applied only to connections made through this proxy,
+    /// distinct from the client's general connection pool.
+    ///
+    /// Not yet wired: reqwest's connection pool is keyed by destination
+    /// host, not by proxy, and `ClientBuilder` has no way to scope
+    /// `pool_max_idle_per_host`/`pool_idle_timeout` to "connections via
+    /// this proxy" versus direct ones. The field is accepted so the API
+    /// shape is ready, but `create_client` currently ignores it.
+    pub pool_settings: Option<ProxyPoolSettings>,
+}
+
+#[derive(Clone)]
+pub struct ProxyPoolSettings {
+    pub max_idle_per_host: Option<usize>,
+    pub idle_timeout: Option<Duration>,
 }
 
+#[derive(Clone, Copy)]
 pub enum ProxyCondition {
     Http,
     Https,
     All,
 }
 
+/// Per-request override of which content codecs are advertised in
+/// `Accept-Encoding` and automatically decoded, replacing whatever the
+/// client was built with. Useful when one endpoint requires a narrower
+/// (or different) negotiation than the rest of the client's traffic --
+/// see `RequestClient::client_for_compression`.
+#[derive(Clone, Copy)]
+pub struct RequestCompression {
+    pub gzip: bool,
+    pub brotli: bool,
+}
+
+#[derive(Clone, Copy)]
 pub enum RedirectSettings {
     NoRedirect,
     LimitedRedirects(i32),
+
+    /// Like `LimitedRedirects`, but re-issues the original method and body
+    /// on 301/302/303 redirects instead of reqwest's default of rewriting
+    /// them to a bodyless GET.
+    ///
+    /// reqwest's `redirect::Policy` only decides whether to follow a
+    /// redirect, not how the retried request is built -- that rewrite is
+    /// hardcoded inside reqwest's own client loop with no public override.
+    /// Honoring this variant means disabling reqwest's redirect following
+    /// entirely and re-issuing each hop by hand; see
+    /// `execute_preserving_method` in `http.rs`.
+    LimitedRedirectsPreserveMethod(i32),
+}
+
+/// Tuning for the QUIC transport underneath `HttpVersionPref::Http3`. See
+/// `ClientSettings::http3_settings`.
+#[derive(Clone, Copy)]
+pub struct Http3Settings {
+    /// Which congestion control algorithm the QUIC connection uses.
+    ///
+    /// Not yet wired: reqwest's `ClientBuilder` doesn't expose a hook to
+    /// choose or swap the `quinn`/`h3` congestion controller -- it's fixed
+    /// to whatever `quinn` defaults to internally. Accepted so the API shape
+    /// is ready; `create_client` currently ignores this field regardless of
+    /// which variant is chosen.
+    pub congestion_controller: QuicCongestionController,
+
+    /// Closes the QUIC connection after this much time with no activity.
+    /// Wired to `ClientBuilder::http3_max_idle_timeout`.
+    pub max_idle_timeout: Option<Duration>,
+
+    /// Enables unreliable QUIC datagrams (RFC 9221) on the connection, for a
+    /// caller that wants to send data without HTTP/3 stream framing or
+    /// retransmission guarantees.
+    ///
+    /// Not yet wired: reqwest's public API has no datagram send/receive
+    /// surface at all -- `quinn`'s datagram support isn't threaded through
+    /// `h3`/reqwest's request/response model, which has no concept of a
+    /// message outside a request or response body. The field is accepted so
+    /// a future reqwest release that adds this can be wired without an API
+    /// break; setting it currently has no effect.
+    pub enable_datagrams: bool,
 }
 
+/// See `Http3Settings::congestion_controller`.
+#[derive(Clone, Copy)]
+pub enum QuicCongestionController {
+    NewReno,
+    Cubic,
+    Bbr,
+}
+
+#[derive(Clone)]
 pub struct TimeoutSettings {
     pub timeout: Option<Duration>,
     pub connect_timeout: Option<Duration>,
     pub keep_alive_timeout: Option<Duration>,
     pub keep_alive_ping: Option<Duration>,
+
+    /// Connect timeout applied only to IPv6 attempts, letting a dual-stack
+    /// client fail fast to IPv4 on networks with broken IPv6. Falls back
+    /// to `connect_timeout` when unset.
+    ///
+    /// Not yet wired: reqwest's `ClientBuilder::connect_timeout` applies to
+    /// the whole happy-eyeballs race and doesn't expose per-family control,
+    /// so this field is currently ignored by `create_client`.
+    pub connect_timeout_ipv6: Option<Duration>,
+
+    /// Connect timeout applied only to IPv4 attempts. See
+    /// `connect_timeout_ipv6` for the same not-yet-wired caveat.
+    pub connect_timeout_ipv4: Option<Duration>,
+
+    /// Bounds the TLS handshake specifically, separately from
+    /// `connect_timeout` -- useful for keeping a generous connect timeout
+    /// (for slow DNS/TCP) while still failing fast when a middlebox
+    /// swallows the `ServerHello` and the handshake itself stalls. On
+    /// expiry, the request fails with `RhttpError::RhttpTlsError` and a
+    /// "handshake timed out" message.
+    ///
+    /// Not yet wired: reqwest's `ClientBuilder::connect_timeout` wraps the
+    /// whole TCP-connect-then-TLS-handshake sequence as one span, with no
+    /// separate hook around just the handshake -- the same
+    /// per-phase-timeout gap `connect_timeout_ipv4`/`connect_timeout_ipv6`
+    /// run into. The field is accepted so the API shape is ready, but the
+    /// handshake is currently only bounded by `connect_timeout` as a whole.
+    pub tls_handshake_timeout: Option<Duration>,
+
+    /// How long to wait for a `100 Continue` (or a final status) after
+    /// sending an `Expect: 100-continue` request before sending the body
+    /// anyway, so a server that doesn't understand the header can't hang
+    /// the request forever. Defaults to about one second, matching curl.
+    ///
+    /// Not yet wired: reqwest doesn't send `Expect: 100-continue` or
+    /// negotiate it in any way -- that behavior lives entirely inside
+    /// hyper's client internals with no public hook to enable it or to
+    /// configure a fallback timeout. The field is accepted so the API
+    /// shape is ready, but every request is currently sent the same way
+    /// it would be with this unset.
+    pub continue_timeout: Option<Duration>,
 }
 
+#[derive(Clone)]
+pub struct TcpSettings {
+    /// Enable TCP Fast Open (TFO) for new connections, saving a round-trip
+    /// on connect by carrying the first bytes of data in the SYN packet.
+    ///
+    /// Not yet wired: reqwest's public `ClientBuilder` only exposes
+    /// `tcp_nodelay`/`tcp_keepalive`, with no hook to set a raw socket
+    /// option like `TCP_FASTOPEN` on the sockets its connector opens. The
+    /// field is accepted so the API shape is ready, but `create_client`
+    /// currently ignores it and every connection is opened exactly as it
+    /// would be with this unset -- which is the same "degrade gracefully"
+    /// behavior a platform/kernel lacking TFO support would need anyway.
+    pub fast_open: bool,
+}
+
+#[derive(Clone)]
 pub struct TlsSettings {
     pub trust_root_certificates: bool,
     pub trusted_root_certificates: Vec<Vec<u8>>,
@@ -63,38 +692,248 @@ pub struct TlsSettings {
     pub min_tls_version: Option<TlsVersion>,
     pub max_tls_version: Option<TlsVersion>,
     pub sni: bool,
+
+    /// Allow sending requests as TLS 1.3 early data (0-RTT) when the session
+    /// supports resumption.
+    ///
+    /// 0-RTT data is replay-unsafe: a network attacker who captures the
+    /// first flight can replay it and have the server process it twice.
+    /// reqwest/rustls only ever attempt early data for requests that are
+    /// sent before the handshake completes, which in practice means the
+    /// first request on a connection resuming a prior session; callers
+    /// should still avoid enabling this for clients that only issue
+    /// non-idempotent requests (POST/PUT/PATCH/DELETE), since there is no
+    /// per-request opt-out once this is enabled on the client.
+    pub enable_early_data: bool,
+
+    /// Custom inspection of the peer's certificate chain (DER-encoded, leaf
+    /// first), invoked after reqwest's own chain/hostname validation
+    /// succeeds. Returning `false` rejects the connection with
+    /// `RhttpError::RhttpTlsError`.
+    ///
+    /// Runs synchronously on the connection task, so it must be fast.
+    ///
+    /// Note: reqwest's rustls backend doesn't expose a way to install a
+    /// custom `rustls::client::danger::ServerCertVerifier`, so this cannot
+    /// currently be hooked into the actual handshake without replacing
+    /// reqwest's TLS setup with a hand-built `rustls::ClientConfig` driven
+    /// through hyper directly. The setting is accepted here so the API
+    /// shape is ready, but it is not yet wired into `create_client`.
+    pub certificate_verify_callback:
+        Option<Arc<dyn Fn(Vec<Vec<u8>>) -> DartFnFuture<bool> + Send + Sync>>,
+
+    /// Best-effort mimicry of a common browser's TLS ClientHello (cipher
+    /// suite order, curve list, extension order), to reduce the chance of
+    /// being fingerprinted (e.g. via JA3) as an automated client.
+    ///
+    /// Not yet wired: reqwest's rustls backend builds its `ClientConfig`
+    /// internally and doesn't expose the cipher suite/extension ordering
+    /// rustls itself picks, so there's no hook to override it without
+    /// replacing reqwest's TLS setup with a hand-built rustls stack. The
+    /// field is accepted so the API shape is ready; setting it currently
+    /// has no effect on the handshake.
+    pub fingerprint_profile: Option<TlsFingerprintProfile>,
+
+    /// Force HTTP/1.1 on connections to these hosts even when ALPN
+    /// negotiates h2, for a server that advertises h2 support but can't
+    /// actually handle h2 framing correctly. Hosts elsewhere are
+    /// unaffected and still negotiate normally.
+    ///
+    /// Not yet wired: reqwest picks the protocol from ALPN's result inside
+    /// its connector, with no hook to override that choice per host after
+    /// the handshake completes -- the same gap `certificate_verify_callback`
+    /// runs into for the handshake itself. The field is accepted so the API
+    /// shape is ready, but every host still uses whatever ALPN negotiates.
+    pub alpn_downgrade_hosts: Vec<String>,
+
+    /// Split-horizon table, keyed by the hostname a request's URL actually
+    /// names: dial `SniOverride::connect_address` instead of resolving that
+    /// hostname normally, while still presenting `SniOverride::sni_name` in
+    /// the TLS ClientHello and validating the peer's certificate against it
+    /// -- for setups where internal DNS answers with a private address for
+    /// a name that must still complete the public-facing handshake.
+    ///
+    /// The connect-address half is wired the same way as
+    /// `DnsSettings::StaticDns::overrides` (`ClientBuilder::resolve_to_addrs`).
+    /// The SNI-name half is not yet wired: reqwest's rustls backend derives
+    /// the `ServerName` it sends and validates from the request URL's own
+    /// host, with no hook to substitute a different one for the handshake.
+    /// Entries are still accepted and the connect-address mapping applied,
+    /// but until that hook exists, the handshake is done against the URL's
+    /// own host, not `sni_name`.
+    pub sni_overrides: HashMap<String, SniOverride>,
+}
+
+/// One entry of `TlsSettings::sni_overrides`.
+#[derive(Clone)]
+pub struct SniOverride {
+    pub connect_address: String,
+    pub sni_name: String,
 }
 
+/// A named preset to mimic when spoofing a TLS ClientHello fingerprint.
+/// See `TlsSettings::fingerprint_profile`.
+#[derive(Clone, Copy)]
+pub enum TlsFingerprintProfile {
+    Chrome,
+    Firefox,
+    Safari,
+}
+
+#[derive(Clone)]
 pub enum DnsSettings {
     StaticDns(StaticDnsSettings),
     DynamicDns(DynamicDnsSettings),
+    SrvDns(SrvDnsSettings),
 }
 
+#[derive(Clone)]
 pub struct StaticDnsSettings {
-    pub overrides: HashMap<String, Vec<String>>,
+    pub overrides: HashMap<String, Vec<DnsOverrideAddress>>,
     pub fallback: Option<String>,
 }
 
+/// One address of a `StaticDnsSettings::overrides` entry.
+#[derive(Clone)]
+pub struct DnsOverrideAddress {
+    pub address: String,
+
+    /// Lower values are tried first; addresses with no priority (or equal
+    /// priorities) keep their relative order from the input list. Lets a
+    /// primary/secondary backend pair be expressed as one override entry
+    /// instead of needing a separate `fallback` per host.
+    ///
+    /// There's no separate weight: `resolve_to_addrs` hands reqwest's
+    /// connector a fixed, ordered address list rather than picking one at
+    /// random per connection, so a priority order already captures "prefer
+    /// this one, fall back to these" -- a weight would only matter if
+    /// connections were distributed across addresses, which isn't how
+    /// this resolution mechanism works.
+    pub priority: Option<u32>,
+}
+
+#[derive(Clone)]
 pub struct DynamicDnsSettings {
     /// A function that takes a hostname and returns a future that resolves to an IP address.
     resolver: Arc<dyn Fn(String) -> DartFnFuture<Vec<String>> + 'static + Send + Sync>,
+
+    /// Bounds how long `resolver` may take before resolution fails with
+    /// `RhttpError::RhttpDnsError`, independent of the connect timeout.
+    pub resolve_timeout: Option<Duration>,
 }
 
+/// Resolves a service name to a backend via a DNS SRV record lookup (e.g.
+/// `_http._tcp.example.com`), for service discovery. The request's URL host
+/// is treated as the service name to query, not a real DNS name.
+///
+/// SRV's wire protocol isn't implemented in Rust here; like
+/// `DynamicDnsSettings::resolver`, the actual query runs on the Dart side
+/// and hands back the answer's records. `select_target` then picks one
+/// following RFC 2782 priority/weight rules, and its target host is
+/// resolved to A/AAAA the same way a plain hostname would be.
+#[derive(Clone)]
+pub struct SrvDnsSettings {
+    resolver: Arc<dyn Fn(String) -> DartFnFuture<Vec<SrvRecord>> + 'static + Send + Sync>,
+
+    /// Bounds how long `resolver` may take before resolution fails with
+    /// `RhttpError::RhttpDnsError`, independent of the connect timeout.
+    pub resolve_timeout: Option<Duration>,
+}
+
+/// One record from a `SrvDnsSettings` query's answer. See `select_target`.
+#[derive(Clone)]
+pub struct SrvRecord {
+    /// The resolved backend's hostname, e.g. `backend-1.example.com`. Still
+    /// needs its own A/AAAA lookup -- SRV records never carry an IP.
+    pub target: String,
+    pub port: u16,
+
+    /// Lower values are preferred; see `select_target`.
+    pub priority: u16,
+
+    /// Relative selection weight within the same `priority`; see
+    /// `select_target`.
+    pub weight: u16,
+}
+
+#[derive(Clone)]
 pub struct ClientCertificate {
     pub certificate: Vec<u8>,
     pub private_key: Vec<u8>,
 }
 
+#[derive(Clone, Copy)]
 pub enum TlsVersion {
     Tls1_2,
     Tls1_3,
 }
 
+/// A canned response registered via `RequestClient::register_mock`. See
+/// `MockMatcher`.
+#[derive(Clone)]
+pub struct MockResponse {
+    pub status_code: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Matches requests by method and/or URL to a canned `MockResponse`, for
+/// exercising networking code in tests without a real server. Both fields
+/// are optional so a matcher can be as broad or narrow as needed; `None`
+/// matches anything for that part. See `RequestClient::register_mock`.
+#[derive(Clone)]
+pub struct MockMatcher {
+    pub method: Option<String>,
+    pub url: Option<String>,
+    pub response: MockResponse,
+}
+
+/// A named request/response body transform pair registered via
+/// `RequestClient::register_codec`, e.g. a proprietary encrypt-then-compress
+/// encoding. Only supported for `HttpBody::Text`/`HttpBody::Bytes` request
+/// bodies and buffered `HttpExpectBody::Text`/`HttpExpectBody::Bytes`
+/// response bodies -- both are run against the whole body at once, so there's
+/// no per-chunk hook for `HttpBody::BytesStream`, the same restriction
+/// `content_hash_algorithm` has.
+#[derive(Clone)]
+pub struct BodyCodec {
+    /// Applied to the whole outgoing request body before it's sent -- after
+    /// reqwest's own request-compression handling, since reqwest never
+    /// transforms outgoing bytes itself (it only negotiates and decodes
+    /// response compression).
+    pub encode_chunk: Arc<dyn Fn(Vec<u8>) -> DartFnFuture<Vec<u8>> + Send + Sync>,
+
+    /// Applied to the whole buffered response body, after reqwest's own
+    /// transparent `Content-Encoding` decompression has already run.
+    pub decode_chunk: Arc<dyn Fn(Vec<u8>) -> DartFnFuture<Vec<u8>> + Send + Sync>,
+}
+
+/// Settings for `RequestClient::enable_har_recording`. See
+/// `RequestClient::export_har`.
+#[derive(Clone)]
+pub struct HarRecordingSettings {
+    /// Header names (case-insensitive) redacted to `"REDACTED"` in recorded
+    /// entries, e.g. `Authorization` or `Cookie`.
+    pub redact_headers: Vec<String>,
+
+    /// Whether to record the request body's content, not just its size.
+    /// Response bodies are never captured, since by the time an entry is
+    /// recorded the response hasn't been decoded yet and consuming it here
+    /// would break the streaming request path; only their `Content-Length`
+    /// is recorded, when the server sent one.
+    pub capture_request_body: bool,
+
+    /// Request bodies larger than this many bytes are recorded by size
+    /// only, regardless of `capture_request_body`.
+    pub max_body_size: usize,
+}
+
 impl Default for ClientSettings {
     fn default() -> Self {
         ClientSettings {
             cookie_settings: None,
             http_version_pref: HttpVersionPref::All,
+            http3_settings: None,
             timeout_settings: None,
             throw_on_status_code: true,
             proxy_settings: None,
@@ -102,6 +941,34 @@ impl Default for ClientSettings {
             tls_settings: None,
             dns_settings: None,
             user_agent: None,
+            cache_settings: None,
+            max_response_header_bytes: None,
+            unix_socket_path: None,
+            on_informational: None,
+            max_decompression_ratio: None,
+            decompression_content_type_rules: Vec::new(),
+            on_pool_event: None,
+            max_total_connections: None,
+            max_concurrent_per_host: None,
+            byte_quota: None,
+            offline_detection: None,
+            on_unauthorized: None,
+            bandwidth_settings: None,
+            on_sign: None,
+            on_generate_span_id: None,
+            require_https: false,
+            reject_ambiguous_content_length: false,
+            raw_capture: None,
+            access_control: None,
+            http2_max_concurrent_streams_per_conn: None,
+            capture_debug_info: false,
+            connect_retries: 0,
+            body_replay_threshold_bytes: None,
+            external_socket_fd: None,
+            android_network_handle: None,
+            tcp_settings: None,
+            on_connection_established: None,
+            referer: true,
         }
     }
 }
@@ -112,8 +979,126 @@ pub struct RequestClient {
     pub(crate) http_version_pref: HttpVersionPref,
     pub(crate) throw_on_status_code: bool,
 
+    /// Headers merged into every request made by this client, unless a
+    /// per-request `remove_headers` entry drops them first.
+    pub(crate) default_headers: HashMap<String, String>,
+
     /// A token that can be used to cancel all requests made by this client.
-    pub(crate) cancel_token: CancellationToken,
+    ///
+    /// Wrapped in a `Mutex` so `RequestClient::cancel_all` can swap in a
+    /// fresh token after cancelling, making the client reusable again.
+    pub(crate) cancel_token: Arc<Mutex<CancellationToken>>,
+
+    /// Lazily-built clients used to execute a single request under a
+    /// per-request client certificate, keyed by `certificate ++ private_key`.
+    ///
+    /// Each of these only carries the identity, not this client's other TLS
+    /// or proxy settings, since `reqwest::Client` doesn't allow swapping the
+    /// identity of an already-built client. They also don't share a
+    /// connection pool with `client` or each other, so mixing per-request
+    /// certificates against the same host defeats connection reuse.
+    pub(crate) cert_clients: Arc<Mutex<HashMap<Vec<u8>, reqwest::Client>>>,
+
+    /// Lazily-built clients used to execute a single request through a
+    /// per-request proxy override, keyed by `condition ++ url`.
+    ///
+    /// Like `cert_clients`, these only carry the proxy, not this client's
+    /// other TLS, timeout, or header settings, and don't share a
+    /// connection pool with `client` or each other -- overriding the
+    /// proxy for a request against a host `client` normally handles
+    /// directly forfeits connection reuse for that request.
+    pub(crate) proxy_clients: Arc<Mutex<HashMap<String, reqwest::Client>>>,
+
+    /// Canned responses registered via `register_mock`, checked before a
+    /// request is sent. Empty by default, so mock mode is fully opt-in and
+    /// costs nothing beyond an empty-vec lock check when unused.
+    pub(crate) mock_matchers: Arc<Mutex<Vec<MockMatcher>>>,
+
+    /// Whether a request with no matching mock falls through to the real
+    /// network (`true`) or fails with `RhttpError::RhttpUnknownError`
+    /// (`false`, the default) once at least one mock is registered.
+    pub(crate) mock_fallthrough: Arc<Mutex<bool>>,
+
+    /// `Some` while HAR recording is enabled via `enable_har_recording`.
+    /// `None` (the default) means requests aren't recorded at all, so
+    /// recording is fully opt-in and costs nothing beyond a lock check when
+    /// unused.
+    pub(crate) har_settings: Arc<Mutex<Option<HarRecordingSettings>>>,
+
+    /// Entries recorded while `har_settings` is `Some`, in request order.
+    /// Drained into a HAR 1.2 document by `export_har`.
+    pub(crate) har_entries: Arc<Mutex<Vec<HarEntry>>>,
+
+    /// Held for the duration of one `on_unauthorized` call, so concurrent
+    /// 401s on this client coalesce into a single refresh. See
+    /// `refresh_authorization`.
+    pub(crate) refresh_lock: Arc<AsyncMutex<()>>,
+
+    /// The `Authorization` value produced by the most recent refresh, used
+    /// to detect that another request already refreshed while this one was
+    /// waiting on `refresh_lock` -- in which case it's reused instead of
+    /// calling `on_unauthorized` again.
+    pub(crate) last_refreshed_authorization: Arc<Mutex<Option<String>>>,
+
+    /// The settings this client was built from, retained so
+    /// `with_overrides` can derive a variant without the caller
+    /// re-specifying everything it isn't changing.
+    pub(crate) settings: ClientSettings,
+
+    /// `Some` when `cookie_settings` configured a cookie limit, retained
+    /// so `cookie_eviction_count` can report how often a limit forced a
+    /// cookie out. `None` when cookies aren't stored at all, or are
+    /// stored via reqwest's own unbounded built-in jar.
+    pub(crate) cookie_jar: Option<Arc<LimitedCookieJar>>,
+
+    /// Per-host semaphores enforcing `ClientSettings::
+    /// max_concurrent_per_host`, created lazily on first use of a host.
+    /// Empty (and never consulted) when that setting is unset.
+    pub(crate) host_semaphores: Arc<Mutex<HashMap<String, Arc<tokio::sync::Semaphore>>>>,
+
+    /// Running total of request + response bytes transferred, shared with
+    /// every clone of this client. Only incremented (see
+    /// `add_bytes_transferred`) and checked against
+    /// `ClientSettings::byte_quota` while that's set; otherwise it stays 0.
+    pub(crate) bytes_transferred: Arc<std::sync::atomic::AtomicU64>,
+
+    /// Lazily-built clients used to execute a single request under a
+    /// per-request `TCP_NODELAY` override, keyed by the requested value.
+    ///
+    /// Like `cert_clients`, these only carry the nodelay setting, not this
+    /// client's other TLS, timeout, or header settings, and don't share a
+    /// connection pool with `client` or each other -- reqwest has no way to
+    /// change a socket option on an already-built `Client`, so a per-request
+    /// override always means opening a dedicated connection for it.
+    pub(crate) nodelay_clients: Arc<Mutex<HashMap<bool, reqwest::Client>>>,
+
+    /// Shared token bucket enforcing `ClientSettings::bandwidth_settings`'s
+    /// `download_bps` across every request made through this client (and
+    /// its clones), so a per-request `BandwidthPriority` weights a share of
+    /// one common cap rather than getting its own independent one. `None`
+    /// when no download cap is set.
+    pub(crate) download_bucket: Option<Arc<AsyncMutex<TokenBucket>>>,
+
+    /// Like `download_bucket`, but for `BandwidthSettings::upload_bps`.
+    pub(crate) upload_bucket: Option<Arc<AsyncMutex<TokenBucket>>>,
+
+    /// Lazily-built clients backing `lease`, keyed by host. Like
+    /// `nodelay_clients`, each only carries a `pool_max_idle_per_host(1)`
+    /// override, not this client's other TLS or proxy settings.
+    pub(crate) lease_clients: Arc<Mutex<HashMap<String, reqwest::Client>>>,
+
+    /// Lazily-built clients backing a per-request `RequestCompression`
+    /// override, keyed by `(gzip, brotli)`. Like `nodelay_clients`, each
+    /// only carries the requested codec set, not this client's other
+    /// settings -- reqwest decides which codecs to advertise and decode at
+    /// `ClientBuilder` time, so a per-request override always means a
+    /// dedicated client.
+    pub(crate) compression_clients: Arc<Mutex<HashMap<(bool, bool), reqwest::Client>>>,
+
+    /// Named body transforms registered via `register_codec`, applied by a
+    /// per-request `codec` name. Empty by default, so codec mode is fully
+    /// opt-in and costs nothing beyond an empty-map lock check when unused.
+    pub(crate) codecs: Arc<Mutex<HashMap<String, BodyCodec>>>,
 }
 
 impl RequestClient {
@@ -124,9 +1109,486 @@ impl RequestClient {
     pub(crate) fn new(settings: ClientSettings) -> Result<RequestClient, RhttpError> {
         create_client(settings)
     }
+
+    /// Builds a new, independent client from a copy of this one's
+    /// settings after applying `f` to it.
+    ///
+    /// Despite the name, the result doesn't share this client's
+    /// connection pool, in-flight cancellation token, or per-request
+    /// certificate cache -- `reqwest::Client` bakes its pool into the
+    /// client at build time, so any settings change (even one unrelated
+    /// to connections, like a timeout) requires building a fresh
+    /// `reqwest::Client` from scratch. This method exists to avoid
+    /// re-declaring unrelated settings, not to avoid the rebuild cost.
+    pub fn with_overrides(
+        &self,
+        f: impl FnOnce(&mut ClientSettings),
+    ) -> Result<RequestClient, RhttpError> {
+        let mut settings = self.settings.clone();
+        f(&mut settings);
+        create_client(settings)
+    }
+
+    /// Cancels all currently in-flight requests made by this client, then
+    /// installs a fresh `CancellationToken` so it can be used for future
+    /// requests instead of staying permanently cancelled.
+    pub fn cancel_all(&self) {
+        let mut cancel_token = self.cancel_token.lock().unwrap();
+        cancel_token.cancel();
+        *cancel_token = CancellationToken::new();
+    }
+
+    /// Returns whether this client's current cancellation token has
+    /// already been cancelled. Since `cancel_all` swaps in a fresh token
+    /// right after cancelling the old one, this is only reliably `true`
+    /// while observed from inside the same synchronous section that
+    /// called `cancel_all` -- by the time an async caller gets scheduled
+    /// again, the token backing this check may already have been
+    /// replaced.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_token.lock().unwrap().is_cancelled()
+    }
+
+    /// Returns a future that resolves once this client's *current*
+    /// cancellation token is cancelled. It's tied to the token at the
+    /// moment this method is called: because `cancel_all` immediately
+    /// installs a fresh token afterward, the returned future settles for
+    /// at most one `cancel_all` call and a future obtained after that
+    /// swap won't observe the earlier cancellation.
+    pub fn cancelled(&self) -> impl std::future::Future<Output = ()> {
+        self.cancel_token.lock().unwrap().clone().cancelled_owned()
+    }
+
+    /// Pre-warms this client's connection pool against `hosts`, so a
+    /// later real request against one of them doesn't pay the connect +
+    /// TLS handshake cost. At most `concurrency` connection attempts run
+    /// at once, each staggered by a small jitter, to avoid spiking
+    /// CPU/network by firing them all at the same instant.
+    ///
+    /// A failed host doesn't abort the others; the returned vector
+    /// reports success/failure per host, in the same order as `hosts`.
+    pub async fn warmup(&self, hosts: Vec<String>, concurrency: usize) -> Vec<(String, bool)> {
+        let concurrency = concurrency.max(1);
+
+        futures_util::stream::iter(hosts.into_iter().enumerate())
+            .map(|(i, host)| {
+                let client = self.client.clone();
+                async move {
+                    let jitter = std::time::Duration::from_millis((i as u64 * 37) % 250);
+                    tokio::time::sleep(jitter).await;
+                    let ok = client.head(&host).send().await.is_ok();
+                    (host, ok)
+                }
+            })
+            .buffered(concurrency)
+            .collect()
+            .await
+    }
+
+    /// Returns a client presenting `certificate` for this one request,
+    /// building and caching it on first use. See `cert_clients` for the
+    /// caveats of these ephemeral, identity-only clients.
+    pub(crate) fn client_for_certificate(
+        &self,
+        certificate: &ClientCertificate,
+    ) -> Result<reqwest::Client, RhttpError> {
+        let key = [
+            certificate.certificate.as_slice(),
+            certificate.private_key.as_slice(),
+        ]
+        .concat();
+
+        if let Some(client) = self.cert_clients.lock().unwrap().get(&key) {
+            return Ok(client.clone());
+        }
+
+        let identity_pem = [
+            certificate.certificate.as_slice(),
+            "\n".as_bytes(),
+            certificate.private_key.as_slice(),
+        ]
+        .concat();
+
+        let client = reqwest::Client::builder()
+            .identity(
+                reqwest::Identity::from_pem(&identity_pem)
+                    .map_err(|e| RhttpError::RhttpUnknownError(format!("{e:?}")))?,
+            )
+            .build()
+            .map_err(|e| RhttpError::RhttpUnknownError(format!("{e:?}")))?;
+
+        self.cert_clients
+            .lock()
+            .unwrap()
+            .insert(key, client.clone());
+
+        Ok(client)
+    }
+
+    /// Returns a client that routes this one request through `proxy`,
+    /// building and caching it on first use. See `proxy_clients` for the
+    /// caveats of these ephemeral, proxy-only clients.
+    pub(crate) fn client_for_proxy(
+        &self,
+        proxy: &CustomProxy,
+    ) -> Result<reqwest::Client, RhttpError> {
+        let condition_tag = match proxy.condition {
+            ProxyCondition::Http => "http",
+            ProxyCondition::Https => "https",
+            ProxyCondition::All => "all",
+        };
+        let key = format!("{condition_tag}:{}", proxy.url);
+
+        if let Some(client) = self.proxy_clients.lock().unwrap().get(&key) {
+            return Ok(client.clone());
+        }
+
+        let reqwest_proxy = match proxy.condition {
+            ProxyCondition::Http => reqwest::Proxy::http(&proxy.url),
+            ProxyCondition::Https => reqwest::Proxy::https(&proxy.url),
+            ProxyCondition::All => reqwest::Proxy::all(&proxy.url),
+        }
+        .map_err(|e| RhttpError::RhttpUnknownError(format!("Error creating proxy: {e:?}")))?;
+
+        let client = reqwest::Client::builder()
+            .proxy(reqwest_proxy)
+            .build()
+            .map_err(|e| RhttpError::RhttpUnknownError(format!("{e:?}")))?;
+
+        self.proxy_clients
+            .lock()
+            .unwrap()
+            .insert(key, client.clone());
+
+        Ok(client)
+    }
+
+    /// Returns a client that executes this one request with `TCP_NODELAY`
+    /// forced to `nodelay`, building and caching it on first use. See
+    /// `nodelay_clients` for the caveats of these ephemeral, nodelay-only
+    /// clients.
+    pub(crate) fn client_for_nodelay(&self, nodelay: bool) -> Result<reqwest::Client, RhttpError> {
+        if let Some(client) = self.nodelay_clients.lock().unwrap().get(&nodelay) {
+            return Ok(client.clone());
+        }
+
+        let client = reqwest::Client::builder()
+            .tcp_nodelay(nodelay)
+            .build()
+            .map_err(|e| RhttpError::RhttpUnknownError(format!("{e:?}")))?;
+
+        self.nodelay_clients
+            .lock()
+            .unwrap()
+            .insert(nodelay, client.clone());
+
+        Ok(client)
+    }
+
+    /// Returns a client that executes this one request advertising and
+    /// decoding only `compression`'s codecs, building and caching it on
+    /// first use. See `compression_clients` for the caveats of these
+    /// ephemeral, compression-only clients.
+    pub(crate) fn client_for_compression(
+        &self,
+        compression: RequestCompression,
+    ) -> Result<reqwest::Client, RhttpError> {
+        let key = (compression.gzip, compression.brotli);
+
+        if let Some(client) = self.compression_clients.lock().unwrap().get(&key) {
+            return Ok(client.clone());
+        }
+
+        let client = reqwest::Client::builder()
+            .gzip(compression.gzip)
+            .brotli(compression.brotli)
+            .build()
+            .map_err(|e| RhttpError::RhttpUnknownError(format!("{e:?}")))?;
+
+        self.compression_clients
+            .lock()
+            .unwrap()
+            .insert(key, client.clone());
+
+        Ok(client)
+    }
+
+    /// Leases a connection to `host` for a burst of requests that need to
+    /// land on the same one. reqwest doesn't expose a handle to a specific
+    /// underlying connection, so this can't pin requests to one literal
+    /// socket the way a raw hyper connection would -- instead it hands back
+    /// a dedicated `pool_max_idle_per_host(1)` client for `host` (built and
+    /// cached the same way `client_for_nodelay` is) paired with a
+    /// capacity-1 permit. `make_http_request*` acquires that permit for the
+    /// duration of any request passed this `ConnectionLease`, serializing
+    /// them so the one connection the pool keeps alive is always the one
+    /// they reuse, rather than a concurrent burst racing the pool into
+    /// opening (and then evicting) several.
+    pub fn lease(&self, host: String) -> Result<ConnectionLease, RhttpError> {
+        let client = match self.lease_clients.lock().unwrap().get(&host) {
+            Some(client) => client.clone(),
+            None => {
+                let client = reqwest::Client::builder()
+                    .pool_max_idle_per_host(1)
+                    .build()
+                    .map_err(|e| RhttpError::RhttpUnknownError(format!("{e:?}")))?;
+                self.lease_clients
+                    .lock()
+                    .unwrap()
+                    .insert(host, client.clone());
+                client
+            }
+        };
+
+        Ok(ConnectionLease {
+            client,
+            permit: Arc::new(tokio::sync::Semaphore::new(1)),
+        })
+    }
+
+    /// Registers a canned response for requests matching `matcher`, so
+    /// `make_http_request*` return it instead of hitting the network.
+    /// Later registrations are checked first, so a specific override can
+    /// be layered on top of an existing broad matcher without removing it.
+    pub fn register_mock(&self, matcher: MockMatcher) {
+        self.mock_matchers.lock().unwrap().push(matcher);
+    }
+
+    /// Removes all registered mocks, returning this client to making real
+    /// requests for everything.
+    pub fn clear_mocks(&self) {
+        self.mock_matchers.lock().unwrap().clear();
+    }
+
+    /// Sets whether a request with no matching mock falls through to the
+    /// real network instead of failing. See `mock_fallthrough`.
+    pub fn set_mock_fallthrough(&self, fallthrough: bool) {
+        *self.mock_fallthrough.lock().unwrap() = fallthrough;
+    }
+
+    /// Registers a named request/response body transform pair, selected
+    /// per request by passing its name as `codec`. Replaces any codec
+    /// already registered under `name`.
+    pub fn register_codec(&self, name: String, codec: BodyCodec) {
+        self.codecs.lock().unwrap().insert(name, codec);
+    }
+
+    /// Removes a previously registered codec. No-op if `name` isn't
+    /// registered.
+    pub fn unregister_codec(&self, name: String) {
+        self.codecs.lock().unwrap().remove(&name);
+    }
+
+    /// Looks up a registered codec by name, for a per-request `codec`
+    /// argument.
+    pub(crate) fn resolve_codec(&self, name: &str) -> Option<BodyCodec> {
+        self.codecs.lock().unwrap().get(name).cloned()
+    }
+
+    /// Looks up a registered mock for `method`/`url`. Returns `Ok(None)`
+    /// both when no mocks are registered at all and when an unmatched
+    /// request is allowed to fall through -- either way the caller should
+    /// proceed with a real request.
+    pub(crate) fn resolve_mock(
+        &self,
+        method: &str,
+        url: &str,
+    ) -> Result<Option<MockResponse>, RhttpError> {
+        let matchers = self.mock_matchers.lock().unwrap();
+        if matchers.is_empty() {
+            return Ok(None);
+        }
+
+        let matched = matchers.iter().rev().find(|matcher| {
+            matcher
+                .method
+                .as_deref()
+                .map(|m| m.eq_ignore_ascii_case(method))
+                .unwrap_or(true)
+                && matcher.url.as_deref().map(|u| u == url).unwrap_or(true)
+        });
+
+        if let Some(matcher) = matched {
+            return Ok(Some(matcher.response.clone()));
+        }
+        drop(matchers);
+
+        if *self.mock_fallthrough.lock().unwrap() {
+            Ok(None)
+        } else {
+            Err(RhttpError::RhttpUnknownError(format!(
+                "no mock registered for {method} {url} and mock_fallthrough is disabled"
+            )))
+        }
+    }
+
+    /// Starts recording every request made by this client into HAR
+    /// entries, retrievable via `export_har`. Overwrites any previously
+    /// configured recording settings; does not clear entries already
+    /// recorded.
+    pub fn enable_har_recording(&self, settings: HarRecordingSettings) {
+        *self.har_settings.lock().unwrap() = Some(settings);
+    }
+
+    /// Stops recording new entries. Entries already recorded remain
+    /// available from `export_har` until `clear_har` is called.
+    pub fn disable_har_recording(&self) {
+        *self.har_settings.lock().unwrap() = None;
+    }
+
+    /// Removes all recorded HAR entries without affecting whether recording
+    /// is currently enabled.
+    pub fn clear_har(&self) {
+        self.har_entries.lock().unwrap().clear();
+    }
+
+    /// Serializes all entries recorded so far into a HAR 1.2 JSON document.
+    pub fn export_har(&self) -> String {
+        crate::utils::har::to_har(&self.har_entries.lock().unwrap())
+    }
+
+    /// How many cookies have been silently evicted so far to stay within
+    /// `CookieSettings`'s limits -- `0` if no limit was ever hit, and
+    /// always `0` if no cookie limit was configured at all. The eviction
+    /// itself is silent (matching browser behavior); this counter is the
+    /// way to observe that it happened.
+    pub fn cookie_eviction_count(&self) -> u64 {
+        self.cookie_jar
+            .as_ref()
+            .map(|jar| jar.eviction_count())
+            .unwrap_or(0)
+    }
+
+    /// Returns the semaphore enforcing `ClientSettings::
+    /// max_concurrent_per_host` for `host`, creating it on first use.
+    /// `None` if that setting isn't configured, in which case the caller
+    /// should skip acquiring a permit entirely.
+    pub(crate) fn semaphore_for_host(&self, host: &str) -> Option<Arc<tokio::sync::Semaphore>> {
+        let max = self.settings.max_concurrent_per_host?;
+        let mut semaphores = self.host_semaphores.lock().unwrap();
+        Some(
+            semaphores
+                .entry(host.to_string())
+                .or_insert_with(|| Arc::new(tokio::sync::Semaphore::new(max)))
+                .clone(),
+        )
+    }
+
+    /// How many request + response bytes this client has transferred so
+    /// far, counted the same way as `ClientSettings::byte_quota`. `0` if
+    /// no quota was ever configured, since nothing increments the counter
+    /// in that case.
+    pub fn bytes_transferred(&self) -> u64 {
+        self.bytes_transferred
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Adds `n` to the running total returned by `bytes_transferred`, and
+    /// is a no-op if no quota is configured. See `ClientSettings::
+    /// byte_quota` for what does and doesn't get counted.
+    pub(crate) fn add_bytes_transferred(&self, n: u64) {
+        if self.settings.byte_quota.is_some() {
+            self.bytes_transferred
+                .fetch_add(n, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    /// `Err(RhttpError::RhttpQuotaExceeded)` if `ClientSettings::byte_quota`
+    /// is set and already met or exceeded by `bytes_transferred`. Checked
+    /// once up front before a request is sent -- a request already in
+    /// flight when the quota is crossed is allowed to finish.
+    pub(crate) fn check_byte_quota(&self) -> Result<(), RhttpError> {
+        match self.settings.byte_quota {
+            Some(quota) if self.bytes_transferred() >= quota => Err(RhttpError::RhttpQuotaExceeded),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the redaction/capture settings to record a request under, or
+    /// `None` if recording is currently disabled -- checked once up front
+    /// so the request path only pays for building a `HarEntry` when someone
+    /// is actually retrieving them.
+    pub(crate) fn har_recording_settings(&self) -> Option<HarRecordingSettings> {
+        self.har_settings.lock().unwrap().clone()
+    }
+
+    pub(crate) fn record_har_entry(&self, entry: HarEntry) {
+        self.har_entries.lock().unwrap().push(entry);
+    }
+
+    /// Runs `ClientSettings::on_unauthorized` to get a new `Authorization`
+    /// value after a 401, coalescing concurrent callers into a single
+    /// refresh. `failed_authorization` is the value the just-failed request
+    /// was sent with; if another caller already refreshed past it while
+    /// this one was waiting for `refresh_lock`, that newer value is reused
+    /// instead of calling the callback again. Returns `None` if there's no
+    /// callback configured or it gives up.
+    pub(crate) async fn refresh_authorization(
+        &self,
+        failed_authorization: Option<&str>,
+    ) -> Option<String> {
+        let callback = self.settings.on_unauthorized.clone()?;
+        let _guard = self.refresh_lock.lock().await;
+
+        let cached = self.last_refreshed_authorization.lock().unwrap().clone();
+        if cached.is_some() && cached.as_deref() != failed_authorization {
+            return cached;
+        }
+
+        let refreshed = callback().await;
+        *self.last_refreshed_authorization.lock().unwrap() = refreshed.clone();
+        refreshed
+    }
+}
+
+/// Builds a redirect policy that follows up to `max_redirects` hops, and,
+/// when `require_https` is set, rejects any hop that would land on a
+/// non-`https://` URL with `RhttpError::RhttpInsecureScheme` instead of
+/// following it. Used both for an explicit `RedirectSettings::LimitedRedirects`
+/// and as the implicit policy when only `require_https` is set.
+fn build_redirect_policy(max_redirects: i32, require_https: bool) -> reqwest::redirect::Policy {
+    let max_redirects = max_redirects.max(0) as usize;
+    reqwest::redirect::Policy::custom(move |attempt| {
+        if require_https && attempt.url().scheme() != "https" {
+            let url = attempt.url().to_string();
+            return attempt.error(RhttpError::RhttpInsecureScheme(url));
+        }
+        if attempt.previous().len() > max_redirects {
+            attempt.stop()
+        } else {
+            attempt.follow()
+        }
+    })
 }
 
 fn create_client(settings: ClientSettings) -> Result<RequestClient, RhttpError> {
+    if settings.unix_socket_path.is_some() {
+        return Err(RhttpError::RhttpUnsupportedError(
+            "Unix domain socket connections are not supported: reqwest does not expose a way to override its transport in this version".to_string(),
+        ));
+    }
+    if settings.external_socket_fd.is_some() {
+        return Err(RhttpError::RhttpUnsupportedError(
+            "sending a request over a caller-provided socket is not supported: reqwest does not expose a way to override its transport in this version".to_string(),
+        ));
+    }
+    if settings.android_network_handle.is_some() {
+        return Err(RhttpError::RhttpUnsupportedError(
+            if cfg!(target_os = "android") {
+                "android_network_handle is not supported: reqwest does not expose a way to bind a connection to a specific Network in this version".to_string()
+            } else {
+                "android_network_handle is only supported on Android".to_string()
+            },
+        ));
+    }
+
+    // Retained on the built client so `RequestClient::with_overrides` can
+    // rebuild from a modified copy without the caller re-specifying
+    // settings it isn't changing.
+    let resolved_settings = settings.clone();
+
+    let mut cookie_jar: Option<Arc<LimitedCookieJar>> = None;
+
     let client: reqwest::Client = {
         let mut client = reqwest::Client::builder();
         if let Some(proxy_settings) = settings.proxy_settings {
@@ -149,18 +1611,43 @@ fn create_client(settings: ClientSettings) -> Result<RequestClient, RhttpError>
         }
 
         if let Some(cookie_settings) = settings.cookie_settings {
-            client = client.cookie_store(cookie_settings.store_cookies);
+            if cookie_settings.store_cookies {
+                if cookie_settings.max_cookies_per_domain.is_some()
+                    || cookie_settings.max_total_cookies.is_some()
+                    || cookie_settings.max_cookie_size_bytes.is_some()
+                {
+                    let jar = Arc::new(LimitedCookieJar::new(
+                        cookie_settings.max_cookies_per_domain,
+                        cookie_settings.max_total_cookies,
+                        cookie_settings.max_cookie_size_bytes,
+                    ));
+                    cookie_jar = Some(jar.clone());
+                    client = client.cookie_provider(jar);
+                } else {
+                    client = client.cookie_store(true);
+                }
+            }
         }
 
         if let Some(redirect_settings) = settings.redirect_settings {
             client = match redirect_settings {
                 RedirectSettings::NoRedirect => client.redirect(reqwest::redirect::Policy::none()),
                 RedirectSettings::LimitedRedirects(max_redirects) => {
-                    client.redirect(reqwest::redirect::Policy::limited(max_redirects as usize))
+                    client.redirect(build_redirect_policy(max_redirects, settings.require_https))
+                }
+                // Followed manually by `execute_preserving_method` in
+                // http.rs, which needs full control over how each hop is
+                // re-issued, so reqwest itself must not also follow it.
+                RedirectSettings::LimitedRedirectsPreserveMethod(_) => {
+                    client.redirect(reqwest::redirect::Policy::none())
                 }
             };
+        } else if settings.require_https {
+            client = client.redirect(build_redirect_policy(DEFAULT_REDIRECT_LIMIT, true));
         }
 
+        client = client.referer(settings.referer);
+
         if let Some(timeout_settings) = settings.timeout_settings {
             if let Some(timeout) = timeout_settings.timeout {
                 client = client.timeout(
@@ -244,6 +1731,17 @@ fn create_client(settings: ClientSettings) -> Result<RequestClient, RhttpError>
             }
 
             client = client.tls_sni(tls_settings.sni);
+            client = client.tls_early_data(tls_settings.enable_early_data);
+
+            for (hostname, sni_override) in tls_settings.sni_overrides {
+                let ip_digested = sni_override.connect_address.digest_ip();
+                let addr = SocketAddr::from_str(ip_digested.as_str()).map_err(|e| {
+                    RhttpError::RhttpUnknownError(format!(
+                        "Invalid IP address: {ip_digested}. {e:?}"
+                    ))
+                })?;
+                client = client.resolve(hostname.as_str(), addr);
+            }
         }
 
         client = match settings.http_version_pref {
@@ -253,24 +1751,48 @@ fn create_client(settings: ClientSettings) -> Result<RequestClient, RhttpError>
             HttpVersionPref::All => client,
         };
 
+        if let Some(http3_settings) = settings.http3_settings {
+            if let Some(max_idle_timeout) = http3_settings.max_idle_timeout {
+                client = client.http3_max_idle_timeout(
+                    max_idle_timeout
+                        .to_std()
+                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
+                );
+            }
+        }
+
+        client = client.http2_max_header_list_size(
+            settings
+                .max_response_header_bytes
+                .unwrap_or(DEFAULT_MAX_RESPONSE_HEADER_BYTES),
+        );
+
+        // Built up here instead of applied immediately, so `access_control`
+        // below can wrap whichever resolver `dns_settings` installed (or
+        // wrap nothing, and do its own resolution) before the single
+        // `client.dns_resolver(...)` call at the end.
+        let mut dns_resolver: Option<Arc<dyn Resolve>> = None;
+
         if let Some(dns_settings) = settings.dns_settings {
             match dns_settings {
                 DnsSettings::StaticDns(settings) => {
                     if let Some(fallback) = settings.fallback {
-                        client = client.dns_resolver(Arc::new(StaticResolver {
+                        dns_resolver = Some(Arc::new(StaticResolver {
                             address: SocketAddr::from_str(fallback.digest_ip().as_str())
                                 .map_err(|e| RhttpError::RhttpUnknownError(format!("{e:?}")))?,
                         }));
                     }
 
                     for dns_override in settings.overrides {
-                        let (hostname, ip) = dns_override;
+                        let (hostname, mut addresses) = dns_override;
                         let hostname = hostname.as_str();
+                        addresses.sort_by_key(|a| a.priority.unwrap_or(0));
+
                         let mut err: Option<String> = None;
-                        let ip = ip
+                        let ip = addresses
                             .into_iter()
-                            .map(|ip| {
-                                let ip_digested = ip.digest_ip();
+                            .map(|address| {
+                                let ip_digested = address.address.digest_ip();
                                 SocketAddr::from_str(ip_digested.as_str()).map_err(|e| {
                                     err = Some(format!("Invalid IP address: {ip_digested}. {e:?}"));
                                     RhttpError::RhttpUnknownError(e.to_string())
@@ -283,19 +1805,49 @@ fn create_client(settings: ClientSettings) -> Result<RequestClient, RhttpError>
                             return Err(RhttpError::RhttpUnknownError(error));
                         }
 
+                        // Not filtered by `access_control` below: `resolve_to_addrs`
+                        // hands these addresses to reqwest through a mechanism
+                        // that bypasses the `Resolve` hook entirely.
                         client = client.resolve_to_addrs(hostname, ip.as_slice());
                     }
                 }
                 DnsSettings::DynamicDns(settings) => {
-                    client = client.dns_resolver(Arc::new(DynamicResolver {
+                    dns_resolver = Some(Arc::new(DynamicResolver {
+                        resolver: settings.resolver,
+                        resolve_timeout: settings
+                            .resolve_timeout
+                            .map(|d| d.to_std())
+                            .transpose()
+                            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
+                    }));
+                }
+                DnsSettings::SrvDns(settings) => {
+                    dns_resolver = Some(Arc::new(SrvResolver {
                         resolver: settings.resolver,
+                        resolve_timeout: settings
+                            .resolve_timeout
+                            .map(|d| d.to_std())
+                            .transpose()
+                            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
                     }));
                 }
             }
         }
 
-        if let Some(user_agent) = settings.user_agent {
-            client = client.user_agent(user_agent);
+        if let Some(access_control) = settings.access_control {
+            dns_resolver = Some(Arc::new(AccessControlResolver {
+                inner: dns_resolver,
+                allow: access_control.allow,
+                deny: access_control.deny,
+                block_private_ranges: access_control.block_private_ranges,
+            }));
+        }
+
+        if let Some(dns_resolver) = dns_resolver {
+            // `dns_resolver` requires a `Sized` resolver, so the trait
+            // object built up above needs one more concrete wrapper before
+            // it can be installed.
+            client = client.dns_resolver(Arc::new(BoxedResolver(dns_resolver)));
         }
 
         client
@@ -303,14 +1855,56 @@ fn create_client(settings: ClientSettings) -> Result<RequestClient, RhttpError>
             .map_err(|e| RhttpError::RhttpUnknownError(format!("{e:?}")))?
     };
 
+    // Kept out of `reqwest::ClientBuilder::default_headers` and merged in
+    // manually per request instead, so a request's `remove_headers` can drop
+    // a default (e.g. `User-Agent`) that reqwest would otherwise re-add.
+    let mut default_headers = HashMap::new();
+    if let Some(user_agent) = settings.user_agent {
+        default_headers.insert("User-Agent".to_string(), user_agent);
+    }
+
     Ok(RequestClient {
         client,
         http_version_pref: settings.http_version_pref,
         throw_on_status_code: settings.throw_on_status_code,
-        cancel_token: CancellationToken::new(),
+        default_headers,
+        cancel_token: Arc::new(Mutex::new(CancellationToken::new())),
+        cert_clients: Arc::new(Mutex::new(HashMap::new())),
+        proxy_clients: Arc::new(Mutex::new(HashMap::new())),
+        mock_matchers: Arc::new(Mutex::new(Vec::new())),
+        mock_fallthrough: Arc::new(Mutex::new(false)),
+        har_settings: Arc::new(Mutex::new(None)),
+        har_entries: Arc::new(Mutex::new(Vec::new())),
+        refresh_lock: Arc::new(AsyncMutex::new(())),
+        last_refreshed_authorization: Arc::new(Mutex::new(None)),
+        cookie_jar,
+        host_semaphores: Arc::new(Mutex::new(HashMap::new())),
+        bytes_transferred: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+        nodelay_clients: Arc::new(Mutex::new(HashMap::new())),
+        lease_clients: Arc::new(Mutex::new(HashMap::new())),
+        compression_clients: Arc::new(Mutex::new(HashMap::new())),
+        codecs: Arc::new(Mutex::new(HashMap::new())),
+        download_bucket: resolved_settings
+            .bandwidth_settings
+            .and_then(|b| b.download_bps)
+            .map(|bps| Arc::new(AsyncMutex::new(TokenBucket::new(bps)))),
+        upload_bucket: resolved_settings
+            .bandwidth_settings
+            .and_then(|b| b.upload_bps)
+            .map(|bps| Arc::new(AsyncMutex::new(TokenBucket::new(bps)))),
+        settings: resolved_settings,
     })
 }
 
+/// A handle returned by `RequestClient::lease`, passed as a per-request
+/// override to pin a burst of requests to one host's connection. See
+/// `RequestClient::lease` for what it can and can't actually guarantee.
+#[derive(Clone)]
+pub struct ConnectionLease {
+    pub(crate) client: reqwest::Client,
+    pub(crate) permit: Arc<tokio::sync::Semaphore>,
+}
+
 struct StaticResolver {
     address: SocketAddr,
 }
@@ -324,13 +1918,30 @@ impl Resolve for StaticResolver {
 
 struct DynamicResolver {
     resolver: Arc<dyn Fn(String) -> DartFnFuture<Vec<String>> + 'static + Send + Sync>,
+
+    /// Bounds how long the user-provided `resolver` may take. `StaticResolver`
+    /// has no equivalent field because its future resolves immediately.
+    resolve_timeout: Option<std::time::Duration>,
 }
 
 impl Resolve for DynamicResolver {
     fn resolve(&self, name: Name) -> Resolving {
         let resolver = self.resolver.clone();
+        let resolve_timeout = self.resolve_timeout;
         Box::pin(async move {
-            let ip = resolver(name.as_str().to_owned()).await;
+            let hostname = name.as_str().to_owned();
+            let resolution = resolver(hostname.clone());
+
+            let ip = match resolve_timeout {
+                Some(timeout) => tokio::time::timeout(timeout, resolution)
+                    .await
+                    .map_err(|_| {
+                        Box::new(RhttpError::RhttpDnsError(format!(
+                            "resolving {hostname} timed out after {timeout:?}"
+                        ))) as Box<dyn std::error::Error + Send + Sync>
+                    })?,
+                None => resolution.await,
+            };
             let ip = ip
                 .into_iter()
                 .map(|ip| {
@@ -351,6 +1962,116 @@ impl Resolve for DynamicResolver {
     }
 }
 
+struct SrvResolver {
+    resolver: Arc<dyn Fn(String) -> DartFnFuture<Vec<SrvRecord>> + 'static + Send + Sync>,
+    resolve_timeout: Option<std::time::Duration>,
+}
+
+impl Resolve for SrvResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let resolver = self.resolver.clone();
+        let resolve_timeout = self.resolve_timeout;
+        Box::pin(async move {
+            let service_name = name.as_str().to_owned();
+            let lookup = resolver(service_name.clone());
+
+            let records = match resolve_timeout {
+                Some(timeout) => tokio::time::timeout(timeout, lookup).await.map_err(|_| {
+                    Box::new(RhttpError::RhttpDnsError(format!(
+                        "resolving SRV records for {service_name} timed out after {timeout:?}"
+                    ))) as Box<dyn std::error::Error + Send + Sync>
+                })?,
+                None => lookup.await,
+            };
+
+            let target =
+                crate::utils::srv::select_target(&records, rand::random()).ok_or_else(|| {
+                    Box::new(RhttpError::RhttpDnsError(format!(
+                        "no SRV records returned for {service_name}"
+                    ))) as Box<dyn std::error::Error + Send + Sync>
+                })?;
+
+            let resolved: Vec<SocketAddr> = tokio::net::lookup_host((target.target.as_str(), 0))
+                .await
+                .map_err(|e| {
+                    Box::new(RhttpError::RhttpDnsError(e.to_string()))
+                        as Box<dyn std::error::Error + Send + Sync>
+                })?
+                .map(|mut addr| {
+                    addr.set_port(target.port);
+                    addr
+                })
+                .collect();
+
+            let addrs: Addrs = Box::new(resolved.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+/// Adapts a `Arc<dyn Resolve>` trait object back into a concrete, `Sized`
+/// type so it can be passed to `ClientBuilder::dns_resolver`, which is
+/// generic over the resolver type.
+struct BoxedResolver(Arc<dyn Resolve>);
+
+impl Resolve for BoxedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        self.0.resolve(name)
+    }
+}
+
+/// See `ClientSettings::access_control`. Wraps `inner` (whichever resolver
+/// `dns_settings` installed, if any) and drops any resolved address that
+/// `access_control::is_blocked` rejects, so the check applies to every
+/// resolution -- including the one reqwest performs again on each redirect
+/// hop, which is what catches DNS rebinding.
+struct AccessControlResolver {
+    /// `None` when no `DnsSettings` was given: this resolver then performs
+    /// its own real lookup instead of delegating.
+    inner: Option<Arc<dyn Resolve>>,
+    allow: Vec<String>,
+    deny: Vec<String>,
+    block_private_ranges: bool,
+}
+
+impl Resolve for AccessControlResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let inner = self.inner.clone();
+        let allow = self.allow.clone();
+        let deny = self.deny.clone();
+        let block_private_ranges = self.block_private_ranges;
+        Box::pin(async move {
+            let hostname = name.as_str().to_owned();
+
+            let resolved: Vec<SocketAddr> = match inner {
+                Some(inner) => inner.resolve(name).await?.collect(),
+                None => tokio::net::lookup_host((hostname.as_str(), 0))
+                    .await
+                    .map_err(|e| {
+                        Box::new(RhttpError::RhttpDnsError(e.to_string()))
+                            as Box<dyn std::error::Error + Send + Sync>
+                    })?
+                    .collect(),
+            };
+
+            let allowed: Vec<SocketAddr> = resolved
+                .into_iter()
+                .filter(|addr| {
+                    !access_control::is_blocked(addr.ip(), &allow, &deny, block_private_ranges)
+                })
+                .collect();
+
+            if allowed.is_empty() {
+                return Err(Box::new(RhttpError::RhttpBlockedAddress(hostname))
+                    as Box<dyn std::error::Error + Send + Sync>);
+            }
+
+            let addrs: Addrs = Box::new(allowed.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
 #[frb(sync)]
 pub fn create_static_resolver_sync(settings: StaticDnsSettings) -> DnsSettings {
     DnsSettings::StaticDns(settings)
@@ -359,8 +2080,470 @@ pub fn create_static_resolver_sync(settings: StaticDnsSettings) -> DnsSettings {
 #[frb(sync)]
 pub fn create_dynamic_resolver_sync(
     resolver: impl Fn(String) -> DartFnFuture<Vec<String>> + 'static + Send + Sync,
+    resolve_timeout: Option<Duration>,
 ) -> DnsSettings {
     DnsSettings::DynamicDns(DynamicDnsSettings {
         resolver: Arc::new(resolver),
+        resolve_timeout,
     })
 }
+
+#[frb(sync)]
+pub fn create_srv_resolver_sync(
+    resolver: impl Fn(String) -> DartFnFuture<Vec<SrvRecord>> + 'static + Send + Sync,
+    resolve_timeout: Option<Duration>,
+) -> DnsSettings {
+    DnsSettings::SrvDns(SrvDnsSettings {
+        resolver: Arc::new(resolver),
+        resolve_timeout,
+    })
+}
+
+/// A `reqwest::cookie::CookieStore` enforcing `CookieSettings`'s cookie
+/// limits on top of the same `cookie_store` crate reqwest's own built-in
+/// jar uses internally, since reqwest doesn't expose limit knobs on that
+/// built-in jar.
+///
+/// Recency is approximated by insertion/update order, not last-access
+/// time -- `cookie_store::CookieStore` doesn't track per-cookie access
+/// timestamps, so a cookie that's merely sent back (not re-set by the
+/// server) doesn't get promoted the way a browser's cache might.
+struct LimitedCookieJar {
+    store: std::sync::RwLock<cookie_store::CookieStore>,
+    order: Mutex<std::collections::VecDeque<(String, String, String)>>,
+    max_cookies_per_domain: Option<usize>,
+    max_total_cookies: Option<usize>,
+    max_cookie_size_bytes: Option<usize>,
+    evictions: std::sync::atomic::AtomicU64,
+}
+
+impl LimitedCookieJar {
+    fn new(
+        max_cookies_per_domain: Option<usize>,
+        max_total_cookies: Option<usize>,
+        max_cookie_size_bytes: Option<usize>,
+    ) -> Self {
+        Self {
+            store: std::sync::RwLock::new(cookie_store::CookieStore::default()),
+            order: Mutex::new(std::collections::VecDeque::new()),
+            max_cookies_per_domain,
+            max_total_cookies,
+            max_cookie_size_bytes,
+            evictions: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    fn eviction_count(&self) -> u64 {
+        self.evictions.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    fn domain_key(domain: &cookie_store::CookieDomain) -> String {
+        match domain {
+            cookie_store::CookieDomain::HostOnly(s) | cookie_store::CookieDomain::Suffix(s) => {
+                s.clone()
+            }
+            cookie_store::CookieDomain::NotPresent | cookie_store::CookieDomain::Empty => {
+                String::new()
+            }
+        }
+    }
+
+    /// Removes the oldest tracked cookie matching `predicate` from both
+    /// `order` and `store`, counting it as an eviction. Returns whether one
+    /// was found.
+    fn evict_oldest(
+        &self,
+        store: &mut cookie_store::CookieStore,
+        order: &mut std::collections::VecDeque<(String, String, String)>,
+        predicate: impl Fn(&(String, String, String)) -> bool,
+    ) -> bool {
+        let Some(pos) = order.iter().position(predicate) else {
+            return false;
+        };
+        let (domain, path, name) = order.remove(pos).unwrap();
+        store.remove(&domain, &path, &name);
+        self.evictions
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        true
+    }
+}
+
+impl reqwest::cookie::CookieStore for LimitedCookieJar {
+    fn set_cookies(
+        &self,
+        cookie_headers: &mut dyn Iterator<Item = &reqwest::header::HeaderValue>,
+        url: &reqwest::Url,
+    ) {
+        let mut store = self.store.write().unwrap();
+        let mut order = self.order.lock().unwrap();
+
+        for header in cookie_headers {
+            let Ok(raw) = std::str::from_utf8(header.as_bytes()) else {
+                continue;
+            };
+
+            let Ok(cookie) = cookie_store::Cookie::parse(raw.to_owned(), url) else {
+                continue;
+            };
+
+            if let Some(max_size) = self.max_cookie_size_bytes {
+                if cookie.name().len() + cookie.value().len() > max_size {
+                    continue;
+                }
+            }
+            let key = (
+                Self::domain_key(&cookie.domain),
+                String::from(&cookie.path),
+                cookie.name().to_string(),
+            );
+
+            match store.insert(cookie, url) {
+                Ok(cookie_store::StoreAction::Inserted)
+                | Ok(cookie_store::StoreAction::UpdatedExisting) => {
+                    order.retain(|existing| existing != &key);
+                    order.push_back(key.clone());
+                }
+                Ok(cookie_store::StoreAction::ExpiredExisting) => {
+                    order.retain(|existing| existing != &key);
+                    continue;
+                }
+                Err(_) => continue,
+            }
+
+            if let Some(max_per_domain) = self.max_cookies_per_domain {
+                while order.iter().filter(|k| k.0 == key.0).count() > max_per_domain {
+                    if !self.evict_oldest(&mut store, &mut order, |k| k.0 == key.0) {
+                        break;
+                    }
+                }
+            }
+
+            if let Some(max_total) = self.max_total_cookies {
+                while order.len() > max_total {
+                    if !self.evict_oldest(&mut store, &mut order, |_| true) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn cookies(&self, url: &reqwest::Url) -> Option<reqwest::header::HeaderValue> {
+        let store = self.store.read().unwrap();
+        let s = store
+            .get_request_values(url)
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        if s.is_empty() {
+            return None;
+        }
+        reqwest::header::HeaderValue::from_str(&s).ok()
+    }
+}
+
+#[cfg(test)]
+mod cookie_jar_tests {
+    use super::*;
+    use reqwest::cookie::CookieStore as _;
+
+    fn set_cookie(jar: &LimitedCookieJar, url: &reqwest::Url, header: &str) {
+        let value = reqwest::header::HeaderValue::from_str(header).unwrap();
+        jar.set_cookies(&mut std::iter::once(&value), url);
+    }
+
+    #[test]
+    fn test_evicts_oldest_cookie_past_per_domain_cap() {
+        let jar = LimitedCookieJar::new(Some(2), None, None);
+        let url = reqwest::Url::parse("https://example.com").unwrap();
+
+        set_cookie(&jar, &url, "a=1");
+        set_cookie(&jar, &url, "b=2");
+        set_cookie(&jar, &url, "c=3");
+
+        let sent = jar.cookies(&url).unwrap();
+        let sent = sent.to_str().unwrap();
+        assert!(
+            !sent.contains("a=1"),
+            "oldest cookie should be evicted: {sent}"
+        );
+        assert!(sent.contains("b=2"));
+        assert!(sent.contains("c=3"));
+        assert_eq!(jar.eviction_count(), 1);
+    }
+
+    #[test]
+    fn test_rejects_oversized_cookie_without_evicting() {
+        let jar = LimitedCookieJar::new(None, None, Some(4));
+        let url = reqwest::Url::parse("https://example.com").unwrap();
+
+        set_cookie(&jar, &url, "a=1");
+        set_cookie(&jar, &url, "toolong=exceedscap");
+
+        let sent = jar.cookies(&url).unwrap();
+        assert_eq!(sent.to_str().unwrap(), "a=1");
+        assert_eq!(jar.eviction_count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod host_semaphore_tests {
+    use super::*;
+
+    #[test]
+    fn test_semaphore_for_host_is_none_without_a_cap() {
+        let client = RequestClient::new_default();
+        assert!(client.semaphore_for_host("example.com").is_none());
+    }
+
+    #[test]
+    fn test_semaphore_for_host_reuses_the_same_semaphore() {
+        let client = RequestClient::new(ClientSettings {
+            max_concurrent_per_host: Some(2),
+            ..ClientSettings::default()
+        })
+        .unwrap();
+
+        let first = client.semaphore_for_host("example.com").unwrap();
+        let second = client.semaphore_for_host("example.com").unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.available_permits(), 2);
+    }
+
+    #[test]
+    fn test_semaphore_for_host_is_independent_per_host() {
+        let client = RequestClient::new(ClientSettings {
+            max_concurrent_per_host: Some(2),
+            ..ClientSettings::default()
+        })
+        .unwrap();
+
+        let a = client.semaphore_for_host("a.example.com").unwrap();
+        let b = client.semaphore_for_host("b.example.com").unwrap();
+        assert!(!Arc::ptr_eq(&a, &b));
+    }
+}
+
+#[cfg(test)]
+mod byte_quota_tests {
+    use super::*;
+
+    #[test]
+    fn test_check_byte_quota_passes_without_a_quota() {
+        let client = RequestClient::new_default();
+        client.add_bytes_transferred(u64::MAX);
+        assert!(client.check_byte_quota().is_ok());
+    }
+
+    #[test]
+    fn test_check_byte_quota_refuses_once_the_quota_is_reached() {
+        let client = RequestClient::new(ClientSettings {
+            byte_quota: Some(100),
+            ..ClientSettings::default()
+        })
+        .unwrap();
+
+        client.add_bytes_transferred(60);
+        assert!(client.check_byte_quota().is_ok());
+
+        client.add_bytes_transferred(40);
+        assert!(matches!(
+            client.check_byte_quota(),
+            Err(RhttpError::RhttpQuotaExceeded)
+        ));
+    }
+
+    #[test]
+    fn test_add_bytes_transferred_is_a_noop_without_a_quota() {
+        let client = RequestClient::new_default();
+        client.add_bytes_transferred(1234);
+        assert_eq!(client.bytes_transferred(), 0);
+    }
+}
+
+#[cfg(test)]
+mod nodelay_client_tests {
+    use super::*;
+
+    #[test]
+    fn test_client_for_nodelay_caches_one_client_per_value() {
+        let client = RequestClient::new_default();
+        assert!(client.nodelay_clients.lock().unwrap().is_empty());
+
+        client.client_for_nodelay(true).unwrap();
+        assert_eq!(client.nodelay_clients.lock().unwrap().len(), 1);
+
+        client.client_for_nodelay(true).unwrap();
+        assert_eq!(client.nodelay_clients.lock().unwrap().len(), 1);
+
+        client.client_for_nodelay(false).unwrap();
+        assert_eq!(client.nodelay_clients.lock().unwrap().len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod lease_tests {
+    use super::*;
+
+    #[test]
+    fn test_lease_caches_one_client_per_host() {
+        let client = RequestClient::new_default();
+        assert!(client.lease_clients.lock().unwrap().is_empty());
+
+        client.lease("example.com".to_string()).unwrap();
+        assert_eq!(client.lease_clients.lock().unwrap().len(), 1);
+
+        client.lease("example.com".to_string()).unwrap();
+        assert_eq!(client.lease_clients.lock().unwrap().len(), 1);
+
+        client.lease("other.example.com".to_string()).unwrap();
+        assert_eq!(client.lease_clients.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_lease_permit_allows_one_holder_at_a_time() {
+        let client = RequestClient::new_default();
+        let lease = client.lease("example.com".to_string()).unwrap();
+        let first = lease.permit.clone().try_acquire_owned().unwrap();
+        assert!(lease.permit.clone().try_acquire_owned().is_err());
+        drop(first);
+        assert!(lease.permit.clone().try_acquire_owned().is_ok());
+    }
+}
+
+#[cfg(test)]
+mod compression_client_tests {
+    use super::*;
+
+    #[test]
+    fn test_client_for_compression_caches_one_client_per_codec_set() {
+        let client = RequestClient::new_default();
+        assert!(client.compression_clients.lock().unwrap().is_empty());
+
+        client
+            .client_for_compression(RequestCompression {
+                gzip: true,
+                brotli: false,
+            })
+            .unwrap();
+        assert_eq!(client.compression_clients.lock().unwrap().len(), 1);
+
+        client
+            .client_for_compression(RequestCompression {
+                gzip: true,
+                brotli: false,
+            })
+            .unwrap();
+        assert_eq!(client.compression_clients.lock().unwrap().len(), 1);
+
+        client
+            .client_for_compression(RequestCompression {
+                gzip: false,
+                brotli: true,
+            })
+            .unwrap();
+        assert_eq!(client.compression_clients.lock().unwrap().len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod http3_client_tests {
+    use super::*;
+
+    #[test]
+    fn test_client_builds_with_bbr_congestion_controller() {
+        let settings = ClientSettings {
+            http_version_pref: HttpVersionPref::Http3,
+            http3_settings: Some(Http3Settings {
+                congestion_controller: QuicCongestionController::Bbr,
+                max_idle_timeout: None,
+                enable_datagrams: false,
+            }),
+            ..ClientSettings::default()
+        };
+        assert!(RequestClient::new(settings).is_ok());
+    }
+}
+
+#[cfg(all(test, not(target_os = "android")))]
+mod android_network_handle_tests {
+    use super::*;
+
+    #[test]
+    fn test_android_network_handle_is_unsupported_off_android() {
+        let settings = ClientSettings {
+            android_network_handle: Some(42),
+            ..ClientSettings::default()
+        };
+        let result = RequestClient::new(settings);
+        assert!(matches!(
+            result,
+            Err(RhttpError::RhttpUnsupportedError(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod codec_tests {
+    use super::*;
+
+    fn identity_codec() -> BodyCodec {
+        BodyCodec {
+            encode_chunk: Arc::new(|bytes| Box::pin(async move { bytes })),
+            decode_chunk: Arc::new(|bytes| Box::pin(async move { bytes })),
+        }
+    }
+
+    #[test]
+    fn test_register_and_resolve_codec() {
+        let client = RequestClient::new_default();
+        assert!(client.resolve_codec("rot13").is_none());
+
+        client.register_codec("rot13".to_string(), identity_codec());
+        assert!(client.resolve_codec("rot13").is_some());
+    }
+
+    #[test]
+    fn test_register_codec_replaces_existing() {
+        let client = RequestClient::new_default();
+        client.register_codec("rot13".to_string(), identity_codec());
+        client.register_codec("rot13".to_string(), identity_codec());
+        assert_eq!(client.codecs.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_unregister_codec() {
+        let client = RequestClient::new_default();
+        client.register_codec("rot13".to_string(), identity_codec());
+        client.unregister_codec("rot13".to_string());
+        assert!(client.resolve_codec("rot13").is_none());
+    }
+
+    #[test]
+    fn test_unregister_codec_missing_is_noop() {
+        let client = RequestClient::new_default();
+        client.unregister_codec("rot13".to_string());
+        assert!(client.resolve_codec("rot13").is_none());
+    }
+}
+
+/// Serializes `settings` to a JSON snapshot, for reproducing an identical
+/// client config in a bug report or caching it across app instances. See
+/// `client_settings_from_snapshot` to rebuild a client from the result, and
+/// `utils::client_snapshot::ClientSettingsSnapshot` for exactly what's
+/// excluded (every callback, plus TLS client certificate key material,
+/// which is never serialized in plaintext).
+#[frb(sync)]
+pub fn export_client_settings_snapshot(settings: ClientSettings) -> Result<String, String> {
+    crate::utils::client_snapshot::serialize(&settings)
+}
+
+/// Rebuilds a `ClientSettings` from a snapshot produced by
+/// `export_client_settings_snapshot`. Every excluded field (callbacks, the
+/// TLS client certificate) comes back unset; reattach them before creating
+/// a client if the original config used them.
+#[frb(sync)]
+pub fn client_settings_from_snapshot(json: String) -> Result<ClientSettings, String> {
+    crate::utils::client_snapshot::deserialize(&json)
+}
@@ -4,14 +4,59 @@ use std::fmt::Display;
 #[derive(Clone, Debug)]
 pub enum RhttpError {
     RhttpCancelError,
-    RhttpTimeoutError,
+    RhttpTimeoutError(TimeoutPhase),
     RhttpRedirectError,
-    RhttpStatusCodeError(u16, Vec<(String, String)>, HttpResponseBody),
+    RhttpStatusCodeError(u16, Vec<(String, String)>, HttpResponseBody, Option<String>),
     RhttpInvalidCertificateError(String),
-    RhttpConnectionError(String),
+    RhttpInvalidUrl(String, String),
+    RhttpConnectionError(String, Vec<(String, String)>),
+    RhttpTlsError(String),
+    RhttpDnsError(String),
+    RhttpHeadersTooLarge,
+    RhttpDecompressionBomb,
+    RhttpJsonError(String),
+    RhttpUnsupportedError(String),
+    RhttpOffline,
+    RhttpInsecureScheme(String),
+    RhttpBlockedAddress(String),
+    RhttpQuotaExceeded,
+    RhttpProtocolError(String),
     RhttpUnknownError(String),
 }
 
+/// Which phase of a request `RhttpError::RhttpTimeoutError` tripped during.
+///
+/// reqwest's public API only ever reports "this timed out" as one flag on
+/// its error type, with no phase breakdown -- `Dns`, `Tls`, and
+/// `AwaitingHeaders` can't currently be distinguished from `Total` through
+/// it, so `map_execute_error` only ever reports `Connect` (via
+/// `reqwest::Error::is_connect`) or falls back to `Total`. `ReadingBody` is
+/// the one phase this crate can determine for certain, since
+/// `read_body_throttled` reads the stream itself and sees the timeout error
+/// directly, rather than through reqwest's single collapsed signal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeoutPhase {
+    Dns,
+    Connect,
+    Tls,
+    AwaitingHeaders,
+    ReadingBody,
+    Total,
+}
+
+impl Display for TimeoutPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeoutPhase::Dns => write!(f, "dns"),
+            TimeoutPhase::Connect => write!(f, "connect"),
+            TimeoutPhase::Tls => write!(f, "tls"),
+            TimeoutPhase::AwaitingHeaders => write!(f, "awaiting_headers"),
+            TimeoutPhase::ReadingBody => write!(f, "reading_body"),
+            TimeoutPhase::Total => write!(f, "total"),
+        }
+    }
+}
+
 // Flutter Rust Bridge only supports anyhow, so we define string constants for the error messages.
 pub(crate) const STREAM_CANCEL_ERROR: &str = "STREAM_CANCEL_ERROR";
 
@@ -19,15 +64,36 @@ impl Display for RhttpError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             RhttpError::RhttpCancelError => write!(f, "RhttpCancelError"),
-            RhttpError::RhttpTimeoutError => write!(f, "RhttpTimeoutError"),
+            RhttpError::RhttpTimeoutError(phase) => write!(f, "RhttpTimeoutError: {phase}"),
             RhttpError::RhttpRedirectError => write!(f, "RhttpRedirectError"),
-            RhttpError::RhttpStatusCodeError(i, _, _) => {
-                write!(f, "RhttpStatusCodeError: {i}")
-            }
+            RhttpError::RhttpStatusCodeError(i, _, _, tag) => match tag {
+                Some(tag) => write!(f, "RhttpStatusCodeError: {i} (tag: {tag})"),
+                None => write!(f, "RhttpStatusCodeError: {i}"),
+            },
             RhttpError::RhttpInvalidCertificateError(d) => {
                 write!(f, "RhttpInvalidCertificateError: {d}")
             }
-            RhttpError::RhttpConnectionError(e) => write!(f, "RhttpConnectionError: {e}"),
+            RhttpError::RhttpInvalidUrl(url, reason) => {
+                write!(f, "RhttpInvalidUrl: {url}: {reason}")
+            }
+            RhttpError::RhttpConnectionError(e, attempts) => {
+                write!(f, "RhttpConnectionError: {e}")?;
+                for (addr, reason) in attempts {
+                    write!(f, "; {addr}: {reason}")?;
+                }
+                Ok(())
+            }
+            RhttpError::RhttpTlsError(e) => write!(f, "RhttpTlsError: {e}"),
+            RhttpError::RhttpDnsError(e) => write!(f, "RhttpDnsError: {e}"),
+            RhttpError::RhttpHeadersTooLarge => write!(f, "RhttpHeadersTooLarge"),
+            RhttpError::RhttpDecompressionBomb => write!(f, "RhttpDecompressionBomb"),
+            RhttpError::RhttpJsonError(e) => write!(f, "RhttpJsonError: {e}"),
+            RhttpError::RhttpUnsupportedError(e) => write!(f, "RhttpUnsupportedError: {e}"),
+            RhttpError::RhttpOffline => write!(f, "RhttpOffline"),
+            RhttpError::RhttpInsecureScheme(url) => write!(f, "RhttpInsecureScheme: {url}"),
+            RhttpError::RhttpBlockedAddress(addr) => write!(f, "RhttpBlockedAddress: {addr}"),
+            RhttpError::RhttpQuotaExceeded => write!(f, "RhttpQuotaExceeded"),
+            RhttpError::RhttpProtocolError(e) => write!(f, "RhttpProtocolError: {e}"),
             RhttpError::RhttpUnknownError(e) => write!(f, "{}", e),
         }
     }
@@ -0,0 +1,41 @@
+use crate::utils::grpc_web;
+use flutter_rust_bridge::frb;
+
+/// A single frame parsed out of a `application/grpc-web` response body: see
+/// `parse_grpc_web_frames_sync`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GrpcWebFrame {
+    /// One gRPC message, already stripped of its 5-byte frame prefix.
+    Message(Vec<u8>),
+
+    /// The trailers frame that ends the response, decoded from its
+    /// HTTP/1.1-style `name: value` header lines.
+    Trailers(Vec<(String, String)>),
+}
+
+/// Frames `message` for a gRPC-Web request: a 5-byte prefix (a compressed
+/// flag byte, then a 4-byte big-endian length) followed by the message
+/// bytes. This is a plain framing helper, not a gRPC stack -- callers still
+/// build and send the request themselves (with `Content-Type:
+/// application/grpc-web`), reusing the client's own TLS/proxy config.
+#[frb(sync)]
+pub fn frame_grpc_web_message(compressed: bool, message: Vec<u8>) -> Vec<u8> {
+    grpc_web::frame_message(compressed, &message)
+}
+
+/// Parses every complete frame out of a gRPC-Web response body, in the
+/// order they appeared, decoding the trailing trailers frame (if present)
+/// into `GrpcWebFrame::Trailers`. Returns an error message if a frame's
+/// declared length runs past the end of `body`, or if the trailers frame
+/// isn't valid header text.
+#[frb(sync)]
+pub fn parse_grpc_web_frames_sync(body: Vec<u8>) -> Result<Vec<GrpcWebFrame>, String> {
+    grpc_web::parse_frames(&body)
+}
+
+/// Reads `grpc-status`/`grpc-message` out of a `GrpcWebFrame::Trailers`'
+/// header list. Returns `(None, None)` if neither is present.
+#[frb(sync)]
+pub fn grpc_web_status(trailers: Vec<(String, String)>) -> (Option<String>, Option<String>) {
+    grpc_web::grpc_status(&trailers)
+}
@@ -1,18 +1,34 @@
+use chrono::Duration;
 use flutter_rust_bridge::for_generated::anyhow;
 use flutter_rust_bridge::{frb, DartFnFuture};
 use futures_util::StreamExt;
-use reqwest::header::{HeaderName, HeaderValue};
-use reqwest::{Method, Response, Url, Version};
+use reqwest::header::{self, HeaderName, HeaderValue};
+use reqwest::{Method, Response, ResponseBuilderExt, Version};
 use std::collections::HashMap;
 use std::error::Error;
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
 use tokio_util::sync::CancellationToken;
 
-use crate::api::client::{ClientSettings, RequestClient};
-use crate::api::error::RhttpError;
+use crate::api::client::{
+    BandwidthPriority, BodyCodec, ClientCertificate, ClientSettings, ConnectionLease, CustomProxy,
+    HarRecordingSettings, MockResponse, RedirectSettings, RequestClient, RequestCompression,
+    SignRequest,
+};
+use crate::api::error::{RhttpError, TimeoutPhase};
 use crate::api::{error, stream};
 use crate::frb_generated::{RustAutoOpaque, StreamSink};
+use crate::utils::har::HarEntry;
+use crate::utils::rate_limiter::TokenBucket;
+use crate::utils::{
+    alt_svc, checksum_trailer, content_disposition, content_hash, decompression_guard, failover,
+    forwarded, gzip_stream, har, jsonp, link_header, multipart_stream, ndjson, query, referer,
+    smuggling, trace_context, url,
+};
 
+#[derive(Clone)]
 pub struct HttpMethod {
     pub method: String,
 }
@@ -23,15 +39,52 @@ impl HttpMethod {
     }
 }
 
+/// The original client's address, protocol, and (optionally) requested host,
+/// for `relay_request` to append to the destination request's
+/// `X-Forwarded-For`/`Forwarded` headers per RFC 7239. This library only
+/// relays requests, it doesn't accept them, so the caller -- whatever
+/// accepted the original client connection -- has to supply these; there's
+/// nothing here to infer them from.
+#[derive(Clone)]
+pub struct ForwardedFor {
+    pub client_addr: String,
+    pub proto: String,
+    pub host: Option<String>,
+}
+
+#[derive(Clone)]
 pub enum HttpHeaders {
     Map(HashMap<String, String>),
     List(Vec<(String, String)>),
+
+    /// A raw HTTP/1.1 header block, for interop tests that need exact
+    /// casing/ordering or technically-invalid values a real client sends.
+    ///
+    /// Not yet wired: reqwest builds requests through `http::HeaderMap`,
+    /// which normalizes names to lowercase and validates values before a
+    /// request is ever sent, so this can't currently bypass that path.
+    /// Using this variant fails the request with
+    /// `RhttpError::RhttpUnsupportedError`.
+    Raw(Vec<u8>),
 }
 
 pub enum HttpBody {
     Text(String),
     Bytes(Vec<u8>),
     BytesStream,
+
+    /// Like `BytesStream`, but each chunk pushed through `body_stream` is
+    /// one already-JSON-encoded array element (e.g. `{"id":1}`), and the
+    /// bytes actually sent are framed as a JSON array -- `[`, then each
+    /// element separated by `,`, then `]` once the stream closes -- so a
+    /// caller can stream-encode a large array element-by-element without
+    /// ever buffering the whole thing. Elements are sent exactly as
+    /// received, with no validation that they're well-formed JSON; that's
+    /// the caller's responsibility, same as it is for `Text`/`Bytes`. Like
+    /// `Text`/`Bytes`, this doesn't set `Content-Type` on its own -- the
+    /// caller sets it explicitly (typically `application/json`).
+    JsonArrayStream,
+
     Form(HashMap<String, String>),
     Multipart(MultipartPayload),
 }
@@ -56,6 +109,14 @@ pub enum MultipartValue {
 
 #[derive(Clone, Copy)]
 pub enum HttpVersionPref {
+    /// Beyond negotiating HTTP/1.0, this buffers the request body instead
+    /// of chunking it (HTTP/1.0 has no `Transfer-Encoding: chunked`) and
+    /// defaults to `Connection: close` unless the caller sets their own.
+    /// A `MultipartValue::File` part is the exception: it is still streamed
+    /// from disk, so a multipart body containing one goes out chunked.
+    /// The outgoing request line's version token itself stays `HTTP/1.1`
+    /// regardless -- hyper's HTTP/1 codec always writes that literally, and
+    /// reqwest doesn't expose a way to override it.
     Http10,
     Http11,
     Http2,
@@ -63,12 +124,124 @@ pub enum HttpVersionPref {
     All,
 }
 
+/// Controls how a request body is framed on the wire.
+///
+/// Only affects HTTP/1.1: HTTP/2 always frames bodies as DATA frames and
+/// has no `Transfer-Encoding`/`Content-Length` distinction, so this is a
+/// no-op there regardless of the chosen variant.
+#[derive(Clone, Copy)]
+pub enum TransferEncoding {
+    /// Let reqwest choose based on whether the body's length is known.
+    Auto,
+    /// Force chunked framing even though the body's length is known, by
+    /// sending it as a single-chunk stream instead of a sized body.
+    Chunked,
+    /// Send with an explicit `Content-Length`, even for an empty body.
+    /// This is already `Auto`'s behavior for `HttpBody::Text`/`Bytes`, so
+    /// it exists mainly to be explicit about intent. A body paced by
+    /// `BandwidthSettings::upload_bps` is the exception: it is streamed and
+    /// so always sent chunked, whichever variant is chosen.
+    ContentLength,
+}
+
+/// A digest to compute over the request body; see
+/// `HttpResponse::request_body_hash`.
+#[derive(Clone, Copy)]
+pub enum ContentHashAlgorithm {
+    /// Not yet wired: computing this would require pulling in a SHA-256
+    /// implementation this crate doesn't currently depend on. Accepted so
+    /// the API shape is ready; requesting it currently leaves
+    /// `HttpResponse::request_body_hash` at `None`.
+    Sha256,
+    Md5,
+}
+
+/// A checksum to compute incrementally while streaming a `HttpBody::
+/// BytesStream` upload, and send as an `x-checksum` trailer once the body
+/// finishes -- so a server (e.g. S3-compatible storage accepting a trailer
+/// checksum over chunked/HTTP2 framing) can validate the upload without
+/// this crate needing a pre-pass over the body to compute it up front. Only
+/// valid with `HttpBody::BytesStream`; see `make_http_request_helper`.
+#[derive(Clone, Copy)]
+pub enum TrailerChecksumAlgorithm {
+    /// CRC-32 (the IEEE 802.3 polynomial, via `crc32fast`), hex-encoded
+    /// lowercase.
+    Crc32,
+
+    /// Not yet wired: no SHA-256 implementation is currently a dependency
+    /// of this crate -- the same gap `ContentHashAlgorithm::Sha256` runs
+    /// into. Unlike that field, requesting this isn't silently ignored:
+    /// sending a request with this algorithm fails up front with
+    /// `RhttpError::RhttpUnsupportedError` rather than sending a body the
+    /// caller believes carries a checksum trailer it doesn't.
+    Sha256,
+}
+
+/// The caller's current trace, to propagate on this request as W3C Trace
+/// Context headers (https://www.w3.org/TR/trace-context/). A fresh span id
+/// for this hop is obtained from `ClientSettings::on_generate_span_id`; if
+/// that hook isn't configured, the request is sent with no tracing headers
+/// at all rather than a `traceparent` with a made-up span id.
+pub struct TraceContext {
+    /// The trace id, as 32 lowercase hex characters (16 bytes).
+    pub trace_id: String,
+
+    /// Whether the trace is sampled -- sets `traceparent`'s flags byte to
+    /// `01` (sampled) or `00` (not sampled).
+    pub sampled: bool,
+
+    /// Passed through verbatim as the `tracestate` header value, unparsed
+    /// and unmodified, per the spec's vendor-opaque format. `None` omits
+    /// the header.
+    pub trace_state: Option<String>,
+}
+
+/// Controls how query parameters that repeat the same key are serialized.
+/// Defaults to `Repeat` when not specified.
 #[derive(Clone, Copy)]
+pub enum QueryArrayEncoding {
+    /// `a=1&a=2`
+    Repeat,
+    /// `a[]=1&a[]=2`
+    Brackets,
+    /// `a=1,2`
+    Comma,
+}
+
+#[derive(Clone)]
 pub enum HttpExpectBody {
     Text,
     Bytes,
+
+    /// Dispatches on the response's `Content-Type`: `application/json` (or
+    /// any `+json` suffix) is read and validated as JSON, `text/*` is read
+    /// as UTF-8 text, and anything else -- including a missing or
+    /// unrecognized `Content-Type` -- falls back to raw bytes. A `charset`
+    /// parameter is not consulted: a `text/*` body that isn't valid UTF-8
+    /// fails with `RhttpError::RhttpUnknownError`.
+    Auto,
+
+    /// Unwraps a JSONP body (`callback({...});`) and returns the inner JSON
+    /// as `HttpResponseBody::Json`, for legacy endpoints that only offer a
+    /// JSONP response. If `callback_name` is given, the wrapper's callback
+    /// name must match it exactly; otherwise the leading identifier is
+    /// used, whatever it is. Fails with `RhttpError::RhttpJsonError` if the
+    /// body isn't a JSONP wrapper (or its name doesn't match) or its inner
+    /// content isn't valid JSON.
+    Jsonp {
+        callback_name: Option<String>,
+    },
+
+    /// Reads and discards the body without buffering it, for a caller that
+    /// only needs the status/headers but must still fully consume the body
+    /// so the underlying connection can be returned to the pool -- dropping
+    /// a response with an unread body forces reqwest to close the
+    /// connection instead of reusing it. Produces
+    /// `HttpResponseBody::Discarded`.
+    Discard,
 }
 
+#[derive(Clone, Copy)]
 pub enum HttpVersion {
     Http09,
     Http10,
@@ -91,11 +264,154 @@ impl HttpVersion {
     }
 }
 
+#[derive(Clone)]
 pub struct HttpResponse {
     pub headers: Vec<(String, String)>,
     pub version: HttpVersion,
     pub status_code: u16,
     pub body: HttpResponseBody,
+
+    /// The declared `Content-Length` of the body, in bytes, read from the
+    /// response headers before the body itself is read -- lets a caller
+    /// decide whether to bother downloading a huge file at all. `None` for
+    /// a chunked or otherwise length-less response, in which case the only
+    /// way to know the size is to read the body and count it.
+    pub content_length: Option<u64>,
+
+    /// The server's parsed `Alt-Svc` alternatives, if any (RFC 7838). Empty
+    /// when the header is absent or advertises `clear`. Informational only
+    /// -- rhttp doesn't act on these automatically.
+    pub alt_svc: Vec<AltSvcEntry>,
+
+    /// The filename suggested by `Content-Disposition` (RFC 6266), if any --
+    /// e.g. for `download_to_file` callers that want to name the file after
+    /// the server's suggestion rather than the request URL. Prefers the
+    /// extended `filename*` parameter over the plain `filename` one, and is
+    /// always sanitized to strip path separators, so it's safe to join onto
+    /// a destination directory without a traversal check of your own. See
+    /// `utils::content_disposition::extract_filename`.
+    pub suggested_filename: Option<String>,
+
+    /// The response's `ETag` header, verbatim (including surrounding quotes
+    /// and any `W/` weak-validator prefix), for use as a later request's
+    /// `if_match`. `None` if the server didn't send one.
+    pub etag: Option<String>,
+
+    /// Trailing headers sent after the body (e.g. gRPC's `grpc-status`), in
+    /// the order the server sent them. Always empty for now: reqwest's
+    /// public `Response` API doesn't expose HTTP/2 trailers and doesn't
+    /// parse HTTP/1.1 chunked trailers, so there's no way for rhttp to read
+    /// them without forking reqwest. Kept as a real field rather than
+    /// omitted so gRPC-style callers have a stable place to read from once
+    /// this becomes possible upstream.
+    pub trailers: Vec<(String, String)>,
+
+    /// The socket address this response was actually received from --
+    /// sourced from `reqwest::Response::remote_addr`. `None` if reqwest
+    /// couldn't determine it (e.g. a mocked or non-TCP transport). Reports
+    /// the underlying connection's peer even when it was reused from the
+    /// pool rather than freshly connected.
+    pub remote_addr: Option<String>,
+
+    /// Not yet wired: unlike `remote_addr`, reqwest doesn't expose the
+    /// local half of the connection anywhere on `Response` or through a
+    /// connector hook this library can observe, so this is always `None`.
+    /// Kept as a real field so callers have a stable place to read from if
+    /// reqwest adds one.
+    pub local_addr: Option<String>,
+
+    /// The raw request-line + headers + body bytes actually sent, captured
+    /// when `ClientSettings::raw_capture` is set. `None` when capture is
+    /// off, and always `None` regardless of the setting for now -- see
+    /// `ClientSettings::raw_capture`.
+    pub raw_request: Option<Vec<u8>>,
+
+    /// The raw status-line + headers + body bytes actually received. Same
+    /// caveats as `raw_request`.
+    pub raw_response: Option<Vec<u8>>,
+
+    /// A summary of what this request actually did, once all per-request
+    /// overrides were applied. `None` unless
+    /// `ClientSettings::capture_debug_info` is set.
+    pub debug_info: Option<RequestDebugInfo>,
+
+    /// The request body's digest, hex-encoded, when a `content_hash_algorithm`
+    /// was requested for this request. Only computed for a fully-buffered
+    /// (`HttpBody::Text`/`Bytes`) body -- one that's already entirely in
+    /// memory before sending, so hashing it costs a second pass rather than
+    /// requiring the body to be replayed. `None` when no algorithm was
+    /// requested, the body was a stream/form/multipart, or the requested
+    /// algorithm isn't wired (see `ContentHashAlgorithm::Sha256`).
+    pub request_body_hash: Option<String>,
+
+    /// Whether this response came from the network or was built locally.
+    /// See `ResponseSource`.
+    pub response_source: ResponseSource,
+}
+
+/// See `HttpResponse::debug_info`.
+#[derive(Clone)]
+pub struct RequestDebugInfo {
+    pub negotiated_version: HttpVersion,
+
+    /// The proxy URL this request was routed through, if any. Only reflects
+    /// a per-request `proxy_override`: when the client's own
+    /// `ProxySettings::CustomProxyList` picks one of several conditional
+    /// proxies, reqwest resolves that internally and doesn't expose which
+    /// one it chose, so this stays `None` in that case even though a proxy
+    /// was used.
+    pub proxy_used: Option<String>,
+
+    /// The client's configured request timeout, if any. Reflects
+    /// `TimeoutSettings::timeout` -- there's no per-request timeout
+    /// override in this API for it to take precedence over.
+    pub timeout_applied: Option<Duration>,
+
+    /// Not yet wired: reqwest's public `Response` doesn't expose whether
+    /// the underlying connection was freshly established or reused from
+    /// the pool, so this is always `None`.
+    pub connection_reused: Option<bool>,
+
+    /// Whether `ClientSettings::on_unauthorized`'s 401-refresh retry fired
+    /// for this request. Doesn't cover a redirect hop taken under
+    /// `RedirectSettings` (reqwest's own redirect policy runs those
+    /// internally and doesn't report back whether it followed one) or a
+    /// `ClientSettings::connect_retries` retry (those only ever happen
+    /// before a response exists to attach this struct to).
+    pub retried: bool,
+}
+
+/// One item delivered by `make_http_request_receive_ndjson`, decoded from
+/// an NDJSON (newline-delimited JSON) response body.
+#[derive(Clone, Debug)]
+pub enum NdjsonLine {
+    /// The line's raw JSON text, valid JSON but left unparsed -- as with
+    /// `HttpResponseBody::Json`, Dart's own `jsonDecode` is the natural
+    /// place to turn it into a structured value.
+    Json(String),
+
+    /// A line that wasn't valid JSON, with the raw line text and
+    /// `serde_json`'s error message. Delivered instead of aborting the
+    /// whole stream, unless `fail_fast_on_malformed_line` is set.
+    Malformed(String, String),
+}
+
+/// One part delivered by `make_http_request_receive_multipart`, parsed from
+/// a `multipart/mixed` or `multipart/x-mixed-replace` (MJPEG-style)
+/// response body. Delivered once the part's closing boundary has arrived,
+/// with its body already collected whole -- see
+/// `multipart_stream::MultipartParser`.
+#[derive(Clone, Debug)]
+pub struct MultipartPart {
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Clone)]
+pub struct AltSvcEntry {
+    pub protocol: String,
+    pub authority: String,
+    pub max_age: Option<u32>,
 }
 
 #[derive(Clone, Debug)]
@@ -103,6 +419,39 @@ pub enum HttpResponseBody {
     Text(String),
     Bytes(Vec<u8>),
     Stream,
+
+    /// The response body, as returned by `HttpExpectBody::Auto` when the
+    /// `Content-Type` was JSON. The text is valid JSON -- already checked
+    /// with `serde_json` -- but left unparsed since Dart's own `jsonDecode`
+    /// is the natural place to turn it into a structured value.
+    Json(String),
+
+    /// The body was read and thrown away without buffering it. See
+    /// `HttpExpectBody::Discard`.
+    Discarded,
+}
+
+/// Where `HttpResponse` actually came from, set consistently on every
+/// response path in `make_http_request_helper`. Defaults to `Network`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResponseSource {
+    /// Fetched from the server for this request.
+    Network,
+
+    /// Not yet wired: served entirely from the local cache with no request
+    /// sent, per `ClientSettings::cache_settings`'s freshness rules. Never
+    /// produced today, since `cache_settings` itself isn't wired -- see its
+    /// doc comment.
+    Cache,
+
+    /// Not yet wired: a conditional request (`If-None-Match`/
+    /// `If-Modified-Since`) came back `304 Not Modified` and the cached body
+    /// was reused. Never produced today, for the same reason as `Cache`.
+    CacheRevalidated,
+
+    /// Built locally from a `MockResponse` registered via
+    /// `RequestClient::resolve_mock`, without any network I/O.
+    Mock,
 }
 
 // It must be async so that frb provides an async context.
@@ -121,7 +470,7 @@ fn register_client_internal(settings: ClientSettings) -> Result<RequestClient, R
 }
 
 pub fn cancel_running_requests(client: &RequestClient) {
-    client.cancel_token.cancel();
+    client.cancel_token.lock().unwrap().cancel();
 }
 
 pub async fn make_http_request(
@@ -130,10 +479,30 @@ pub async fn make_http_request(
     method: HttpMethod,
     url: String,
     query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
     headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
     body: Option<HttpBody>,
     body_stream: Option<stream::Dart2RustStreamReceiver>,
+    transfer_encoding: Option<TransferEncoding>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
     expect_body: HttpExpectBody,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+    operation_deadline: Option<OperationDeadline>,
     on_cancel_token: impl Fn(CancellationToken) -> DartFnFuture<()>,
     cancelable: bool,
 ) -> Result<HttpResponse, RhttpError> {
@@ -146,16 +515,38 @@ pub async fn make_http_request(
     tokio::select! {
         _ = cancel_tokens.request_cancel_token.cancelled() => Err(RhttpError::RhttpCancelError),
         _ = cancel_tokens.client_cancel_token.cancelled() => Err(RhttpError::RhttpCancelError),
+        _ = await_operation_deadline(&operation_deadline) => {
+            Err(RhttpError::RhttpTimeoutError(TimeoutPhase::Total))
+        },
         response = make_http_request_inner(
             client,
             settings,
             method,
             url.to_owned(),
             query,
+            query_array_encoding,
             headers,
+            remove_headers,
+            suppress_default_accept,
+            throw_on_status,
+            http_version_override,
             body,
             body_stream,
+            transfer_encoding,
+            client_certificate,
+            proxy_override,
             expect_body,
+            tag,
+            idempotency_key,
+            if_match,
+            content_hash_algorithm,
+            trailer_checksum,
+            codec,
+            trace_context,
+            tcp_nodelay,
+            connection_lease,
+            request_compression,
+            bandwidth_priority,
         ) => response,
     }
 }
@@ -167,7 +558,7 @@ struct RequestCancelTokens {
 
 fn build_cancel_tokens(client: Option<RustAutoOpaque<RequestClient>>) -> RequestCancelTokens {
     let client_cancel_token = match client {
-        Some(client) => Some(client.try_read().unwrap().cancel_token.clone()),
+        Some(client) => Some(client.try_read().unwrap().cancel_token.lock().unwrap().clone()),
         None => None,
     }
     .unwrap_or_else(|| CancellationToken::new());
@@ -178,64 +569,70 @@ fn build_cancel_tokens(client: Option<RustAutoOpaque<RequestClient>>) -> Request
     }
 }
 
-async fn make_http_request_inner(
-    client: Option<RustAutoOpaque<RequestClient>>,
-    settings: Option<ClientSettings>,
-    method: HttpMethod,
-    url: String,
-    query: Option<Vec<(String, String)>>,
-    headers: Option<HttpHeaders>,
-    body: Option<HttpBody>,
-    body_stream: Option<stream::Dart2RustStreamReceiver>,
-    expect_body: HttpExpectBody,
-) -> Result<HttpResponse, RhttpError> {
-    let response = make_http_request_helper(
-        client,
-        settings,
-        method,
-        url,
-        query,
-        headers,
-        body,
-        body_stream,
-        Some(expect_body),
-    )
-    .await?;
+/// A deadline shared across several requests that make up one logical
+/// multi-step operation (e.g. login, then fetch, then confirm), created once
+/// via `create_operation_deadline` and passed to each request as
+/// `operation_deadline`. Each request races itself against the time
+/// remaining until the shared deadline instead of the caller recomputing a
+/// shrinking per-request timeout by hand -- a request still in flight when
+/// the deadline passes fails with `RhttpError::RhttpTimeoutError`, the same
+/// error a per-client `TimeoutSettings::timeout` produces on expiry.
+#[derive(Clone)]
+pub struct OperationDeadline {
+    pub(crate) deadline: std::time::Instant,
+}
 
-    Ok(HttpResponse {
-        headers: header_to_vec(response.headers()),
-        version: HttpVersion::from_version(response.version()),
-        status_code: response.status().as_u16(),
-        body: match expect_body {
-            HttpExpectBody::Text => HttpResponseBody::Text(
-                response
-                    .text()
-                    .await
-                    .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
-            ),
-            HttpExpectBody::Bytes => HttpResponseBody::Bytes(
-                response
-                    .bytes()
-                    .await
-                    .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?
-                    .to_vec(),
-            ),
-        },
-    })
+/// Creates an `OperationDeadline` that expires `remaining` from now.
+#[frb(sync)]
+pub fn create_operation_deadline(remaining: Duration) -> OperationDeadline {
+    OperationDeadline {
+        deadline: std::time::Instant::now()
+            + remaining.to_std().unwrap_or(std::time::Duration::ZERO),
+    }
 }
 
-pub async fn make_http_request_receive_stream(
+/// Resolves once `operation_deadline` has passed, or never resolves if
+/// there is none -- for a `tokio::select!` arm that races a request against
+/// a shared `OperationDeadline` the same way the existing arms race it
+/// against `request_cancel_token`/`client_cancel_token`.
+async fn await_operation_deadline(operation_deadline: &Option<OperationDeadline>) {
+    match operation_deadline {
+        Some(operation_deadline) => {
+            tokio::time::sleep_until(tokio::time::Instant::from_std(operation_deadline.deadline))
+                .await
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Issues `url`, then keeps following each response's `Link: <...>;
+/// rel="next"` header (RFC 5988), delivering one `HttpResponse` per page,
+/// until a page has no `next` link or `max_pages` is reached. `query`/
+/// `query_array_encoding` only apply to the initial request -- a `next` link
+/// already carries its own full query string. Every page reuses the initial
+/// request's method and headers, but never resends a body: pagination is
+/// assumed to be a read, and a body generally can't be replayed across pages
+/// anyway.
+pub async fn make_http_request_paginated(
     client: Option<RustAutoOpaque<RequestClient>>,
     settings: Option<ClientSettings>,
     method: HttpMethod,
     url: String,
     query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
     headers: Option<HttpHeaders>,
-    body: Option<HttpBody>,
-    body_stream: Option<stream::Dart2RustStreamReceiver>,
-    stream_sink: StreamSink<Vec<u8>>,
-    on_response: impl Fn(HttpResponse) -> DartFnFuture<()>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    expect_body: HttpExpectBody,
+    tag: Option<String>,
+    max_pages: Option<u32>,
+    stream_sink: StreamSink<HttpResponse>,
     on_error: impl Fn(RhttpError) -> DartFnFuture<()>,
+    operation_deadline: Option<OperationDeadline>,
     on_cancel_token: impl Fn(CancellationToken) -> DartFnFuture<()>,
     cancelable: bool,
 ) {
@@ -254,183 +651,2268 @@ pub async fn make_http_request_receive_stream(
             let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
             on_error(RhttpError::RhttpCancelError).await;
         },
-        _ = make_http_request_receive_stream_inner(
+        _ = await_operation_deadline(&operation_deadline) => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpTimeoutError(TimeoutPhase::Total)).await;
+        },
+        _ = make_http_request_paginated_inner(
             client,
             settings,
             method,
             url.to_owned(),
             query,
+            query_array_encoding,
             headers,
-            body,
-            body_stream,
+            remove_headers,
+            suppress_default_accept,
+            throw_on_status,
+            http_version_override,
+            client_certificate,
+            proxy_override,
+            expect_body,
+            tag,
+            max_pages,
             stream_sink.clone(),
-            on_response,
             &on_error,
         ) => {},
     }
 }
 
-async fn make_http_request_receive_stream_inner(
+async fn make_http_request_paginated_inner(
     client: Option<RustAutoOpaque<RequestClient>>,
     settings: Option<ClientSettings>,
     method: HttpMethod,
     url: String,
     query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
     headers: Option<HttpHeaders>,
-    body: Option<HttpBody>,
-    body_stream: Option<stream::Dart2RustStreamReceiver>,
-    stream_sink: StreamSink<Vec<u8>>,
-    on_response: impl Fn(HttpResponse) -> DartFnFuture<()>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    expect_body: HttpExpectBody,
+    tag: Option<String>,
+    max_pages: Option<u32>,
+    stream_sink: StreamSink<HttpResponse>,
     on_error: &impl Fn(RhttpError) -> DartFnFuture<()>,
 ) {
-    let response = make_http_request_helper(
-        client,
-        settings,
-        method,
-        url,
-        query,
-        headers,
-        body,
-        body_stream,
-        None,
-    )
-    .await;
+    let mut next_url = Some(url);
+    let mut query = query;
+    let mut pages_fetched: u32 = 0;
 
-    let response: Response = match response {
-        Ok(res) => res,
-        Err(e) => {
-            on_error(e.clone()).await;
-            return;
+    while let Some(current_url) = next_url {
+        if max_pages.is_some_and(|max| pages_fetched >= max) {
+            break;
         }
-    };
-
-    let http_response = HttpResponse {
-        headers: header_to_vec(response.headers()),
-        version: HttpVersion::from_version(response.version()),
-        status_code: response.status().as_u16(),
-        body: HttpResponseBody::Stream,
-    };
 
-    on_response(http_response).await;
+        let response = make_http_request_inner(
+            client.clone(),
+            settings.clone(),
+            method.clone(),
+            current_url,
+            query.take(),
+            query_array_encoding,
+            headers.clone(),
+            remove_headers.clone(),
+            suppress_default_accept,
+            throw_on_status,
+            http_version_override,
+            None,
+            None,
+            None,
+            client_certificate.clone(),
+            proxy_override.clone(),
+            expect_body.clone(),
+            tag.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
 
-    let mut stream = response.bytes_stream();
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => {
+                on_error(e).await;
+                return;
+            }
+        };
 
-    while let Some(chunk) = stream.next().await {
-        let chunk = chunk.inspect_err(|e| {
-            let _ = stream_sink.add_error(anyhow::anyhow!(e.to_string()));
-        });
+        pages_fetched += 1;
+        next_url = find_next_link(&response);
 
-        if chunk.is_err() {
+        if stream_sink.add(response).is_err() {
             return;
         }
+    }
+}
 
-        let result = stream_sink.add(chunk.unwrap().to_vec()).inspect_err(|e| {
-            let _ = stream_sink.add_error(anyhow::anyhow!(e.to_string()));
-        });
+/// Reads the `Link` header(s) off an already-assembled `HttpResponse` and
+/// returns the `rel="next"` target, if any.
+fn find_next_link(response: &HttpResponse) -> Option<String> {
+    let entries: Vec<link_header::LinkHeaderEntry> = response
+        .headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("link"))
+        .flat_map(|(_, value)| link_header::parse(value))
+        .collect();
+    link_header::find_rel(&entries, "next").map(|url| url.to_string())
+}
 
-        if result.is_err() {
-            return;
-        }
-    }
+/// The result of `make_http_request_failover`: the response from whichever
+/// endpoint succeeded, plus which endpoint produced it.
+pub struct FailoverResponse {
+    pub response: HttpResponse,
+
+    /// The full URL (`base_url` joined with `path`) that produced
+    /// `response`.
+    pub endpoint: String,
 }
 
-/// This function is used to make an HTTP request without any response handling.
-async fn make_http_request_helper(
+/// Tries `base_urls` in order, each joined with `path`, and returns the
+/// first response that succeeds. Like `make_http_request_paginated`, this
+/// never sends a body -- failover is assumed to be a read, and a body
+/// can't be safely resent against a second endpoint without the caller
+/// producing a fresh copy of it each time.
+///
+/// An attempt falls through to the next endpoint on a connection error
+/// (DNS/TCP/TLS failure, timeout -- see `failover::should_failover`) or a
+/// response whose status is listed in `failover_status_codes`. Anything
+/// else -- including any 4xx not in that list, which means the request
+/// itself was rejected rather than the endpoint being unhealthy -- is
+/// returned to the caller immediately without trying the remaining
+/// endpoints. Each individual attempt still applies
+/// `ClientSettings::connect_retries`'s own retry/backoff as usual before
+/// being counted as failed. The last endpoint's outcome is always
+/// returned, whether or not it would otherwise have triggered failover,
+/// since there's nowhere left to fail over to.
+pub async fn make_http_request_failover(
     client: Option<RustAutoOpaque<RequestClient>>,
     settings: Option<ClientSettings>,
     method: HttpMethod,
-    url: String,
+    base_urls: Vec<String>,
+    path: String,
     query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
     headers: Option<HttpHeaders>,
-    body: Option<HttpBody>,
-    body_stream: Option<stream::Dart2RustStreamReceiver>,
-    expect_body: Option<HttpExpectBody>,
-) -> Result<Response, RhttpError> {
-    let client: RequestClient = match client {
-        Some(client) => client.try_read().unwrap().clone(),
-        None => match settings {
-            Some(settings) => RequestClient::new(settings)
-                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
-            None => RequestClient::new_default(),
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    expect_body: HttpExpectBody,
+    tag: Option<String>,
+    failover_status_codes: Option<Vec<u16>>,
+    operation_deadline: Option<OperationDeadline>,
+    on_cancel_token: impl Fn(CancellationToken) -> DartFnFuture<()>,
+    cancelable: bool,
+) -> Result<FailoverResponse, RhttpError> {
+    let cancel_tokens = build_cancel_tokens(client.clone());
+
+    if cancelable {
+        on_cancel_token(cancel_tokens.request_cancel_token.clone()).await;
+    }
+
+    tokio::select! {
+        _ = cancel_tokens.request_cancel_token.cancelled() => Err(RhttpError::RhttpCancelError),
+        _ = cancel_tokens.client_cancel_token.cancelled() => Err(RhttpError::RhttpCancelError),
+        _ = await_operation_deadline(&operation_deadline) => {
+            Err(RhttpError::RhttpTimeoutError(TimeoutPhase::Total))
         },
-    };
+        response = make_http_request_failover_inner(
+            client,
+            settings,
+            method,
+            base_urls,
+            path,
+            query,
+            query_array_encoding,
+            headers,
+            remove_headers,
+            suppress_default_accept,
+            throw_on_status,
+            http_version_override,
+            client_certificate,
+            proxy_override,
+            expect_body,
+            tag,
+            failover_status_codes.unwrap_or_default(),
+        ) => response,
+    }
+}
 
-    let request = {
-        let mut request = client.client.request(
-            method.to_method(),
-            Url::parse(&url).map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
-        );
+async fn make_http_request_failover_inner(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    base_urls: Vec<String>,
+    path: String,
+    query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
+    headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    expect_body: HttpExpectBody,
+    tag: Option<String>,
+    failover_status_codes: Vec<u16>,
+) -> Result<FailoverResponse, RhttpError> {
+    if base_urls.is_empty() {
+        return Err(RhttpError::RhttpUnknownError(
+            "base_urls must not be empty".to_string(),
+        ));
+    }
 
-        request = match client.http_version_pref {
-            HttpVersionPref::Http10 => request.version(Version::HTTP_10),
-            HttpVersionPref::Http11 => request.version(Version::HTTP_11),
-            HttpVersionPref::Http2 => request.version(Version::HTTP_2),
-            HttpVersionPref::Http3 => request.version(Version::HTTP_3),
-            HttpVersionPref::All => request,
-        };
+    for (i, base_url) in base_urls.iter().enumerate() {
+        let is_last = i == base_urls.len() - 1;
+        let endpoint = failover::join(base_url, &path);
 
-        if let Some(query) = query {
-            request = request.query(&query);
-        }
+        let result = make_http_request_inner(
+            client.clone(),
+            settings.clone(),
+            method.clone(),
+            endpoint.clone(),
+            query.clone(),
+            query_array_encoding,
+            headers.clone(),
+            remove_headers.clone(),
+            suppress_default_accept,
+            throw_on_status,
+            http_version_override,
+            None,
+            None,
+            None,
+            client_certificate.clone(),
+            proxy_override.clone(),
+            expect_body.clone(),
+            tag.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
 
-        match headers {
-            Some(HttpHeaders::Map(map)) => {
-                for (k, v) in map {
-                    let header_name = HeaderName::from_str(&k)
-                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
-                    let header_value = HeaderValue::from_str(&v)
-                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
-                    request = request.header(header_name, header_value);
+        match result {
+            Ok(response) => {
+                if is_last
+                    || !failover::should_failover(
+                        Some(response.status_code),
+                        &failover_status_codes,
+                    )
+                {
+                    return Ok(FailoverResponse { response, endpoint });
                 }
             }
-            Some(HttpHeaders::List(list)) => {
-                for (k, v) in list {
-                    let header_name = HeaderName::from_str(&k)
-                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
-                    let header_value = HeaderValue::from_str(&v)
-                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
-                    request = request.header(header_name, header_value);
+            Err(e) => {
+                let status_code = match &e {
+                    RhttpError::RhttpStatusCodeError(code, ..) => Some(*code),
+                    _ => None,
+                };
+                if is_last || !failover::should_failover(status_code, &failover_status_codes) {
+                    return Err(e);
                 }
             }
-            None => (),
-        };
-
-        request = match body {
-            Some(HttpBody::Text(text)) => request.body(text),
-            Some(HttpBody::Bytes(bytes)) => request.body(bytes),
-            Some(HttpBody::BytesStream) => {
-                let stream = body_stream
-                    .expect("body_stream should exist for HttpBody::BytesStream")
-                    .receiver
-                    .map(|v| Ok::<Vec<u8>, RhttpError>(v));
-
-                let body = reqwest::Body::wrap_stream(stream);
-                request.body(body)
-            }
-            Some(HttpBody::Form(form)) => request.form(&form),
-            Some(HttpBody::Multipart(body)) => {
-                let mut form = reqwest::multipart::Form::new();
-                for (k, v) in body.parts {
-                    let mut part = match v.value {
-                        MultipartValue::Text(text) => reqwest::multipart::Part::text(text),
-                        MultipartValue::Bytes(bytes) => reqwest::multipart::Part::bytes(bytes),
-                        MultipartValue::File(file) => {
-                            let file = tokio::fs::File::open(file).await.map_err(|_| {
-                                RhttpError::RhttpUnknownError("Failed to open file".to_string())
-                            })?;
-                            reqwest::multipart::Part::stream(reqwest::Body::wrap_stream(
-                                tokio_util::io::ReaderStream::new(file),
-                            ))
-                        }
-                    };
+        }
+    }
 
-                    if let Some(file_name) = v.file_name {
-                        part = part.file_name(file_name);
-                    }
+    unreachable!("the last base_url attempt above always returns")
+}
 
-                    if let Some(content_type) = v.content_type {
-                        part = part
-                            .mime_str(&content_type)
+async fn make_http_request_inner(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    url: String,
+    query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
+    headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    body: Option<HttpBody>,
+    body_stream: Option<stream::Dart2RustStreamReceiver>,
+    transfer_encoding: Option<TransferEncoding>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    expect_body: HttpExpectBody,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+) -> Result<HttpResponse, RhttpError> {
+    let (response, client, debug_info, request_body_hash, response_source, body_codec) =
+        make_http_request_helper(
+            client,
+            settings,
+            method,
+            url,
+            query,
+            query_array_encoding,
+            headers,
+            remove_headers,
+            suppress_default_accept,
+            throw_on_status,
+            http_version_override,
+            body,
+            body_stream,
+            transfer_encoding,
+            client_certificate,
+            proxy_override,
+            Some(expect_body.clone()),
+            tag,
+            idempotency_key,
+            if_match,
+            content_hash_algorithm,
+            trailer_checksum,
+            codec,
+            trace_context,
+            tcp_nodelay,
+            connection_lease,
+            request_compression,
+            bandwidth_priority,
+        )
+        .await?;
+
+    let alt_svc = parse_alt_svc(response.headers());
+    let suggested_filename = parse_suggested_filename(response.headers());
+    let etag = parse_etag(response.headers());
+    let headers = header_to_vec(response.headers());
+    let version = HttpVersion::from_version(response.version());
+    let status_code = response.status().as_u16();
+    let remote_addr = response.remote_addr().map(|a| a.to_string());
+    let content_length = response.content_length();
+
+    let body = match expect_body {
+        HttpExpectBody::Text => HttpResponseBody::Text(
+            String::from_utf8(
+                decode_response_bytes(
+                    &body_codec,
+                    read_body_throttled(&client, response, bandwidth_priority).await?,
+                )
+                .await,
+            )
+            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
+        ),
+        HttpExpectBody::Bytes => HttpResponseBody::Bytes(
+            decode_response_bytes(
+                &body_codec,
+                read_body_throttled(&client, response, bandwidth_priority).await?,
+            )
+            .await,
+        ),
+        HttpExpectBody::Auto => decode_auto(&client, response, bandwidth_priority).await?,
+        HttpExpectBody::Jsonp { callback_name } => {
+            let bytes = read_body_throttled(&client, response, bandwidth_priority).await?;
+            let text = String::from_utf8(bytes)
+                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+            let inner = jsonp::unwrap(&text, callback_name.as_deref())
+                .map_err(RhttpError::RhttpJsonError)?;
+            serde_json::from_str::<serde_json::Value>(&inner)
+                .map_err(|e| RhttpError::RhttpJsonError(e.to_string()))?;
+            HttpResponseBody::Json(inner)
+        }
+        HttpExpectBody::Discard => {
+            drain_body(&client, response, bandwidth_priority).await?;
+            HttpResponseBody::Discarded
+        }
+    };
+
+    Ok(HttpResponse {
+        headers,
+        version,
+        status_code,
+        body,
+        content_length,
+        alt_svc,
+        suggested_filename,
+        etag,
+        remote_addr,
+        local_addr: None,
+        raw_request: None,
+        raw_response: None,
+        trailers: Vec::new(),
+        debug_info,
+        request_body_hash,
+        response_source,
+    })
+}
+
+/// Request-side data collected for one HAR entry while `request` is still
+/// available, before it's consumed by sending. Turned into a `HarEntry` by
+/// `to_entry` once the response is known. See
+/// `RequestClient::enable_har_recording`.
+struct HarCapture {
+    started_date_time: String,
+    start: std::time::Instant,
+    method: String,
+    url: String,
+    request_headers: Vec<(String, String)>,
+    request_body_size: usize,
+    request_body_text: Option<String>,
+    redact_headers: Vec<String>,
+    tag: Option<String>,
+}
+
+impl HarCapture {
+    fn to_entry(
+        &self,
+        status_code: u16,
+        response_headers: Vec<(String, String)>,
+        response_body_size: Option<u64>,
+    ) -> HarEntry {
+        HarEntry {
+            started_date_time: self.started_date_time.clone(),
+            time_ms: self.start.elapsed().as_secs_f64() * 1000.0,
+            method: self.method.clone(),
+            url: self.url.clone(),
+            request_headers: self.request_headers.clone(),
+            request_body_size: self.request_body_size,
+            request_body_text: self.request_body_text.clone(),
+            status_code,
+            response_headers,
+            response_body_size,
+            tag: self.tag.clone(),
+        }
+    }
+}
+
+/// Best-effort preview of `body` for HAR recording: its byte size (`0` when
+/// not cheaply known, e.g. for a multipart form) and, only when
+/// `capture_request_body` is set and the size is within `max_body_size`,
+/// its text -- and only for `Text`/`Bytes` bodies that decode as UTF-8.
+fn preview_request_body(
+    body: &Option<HttpBody>,
+    settings: &HarRecordingSettings,
+) -> (usize, Option<String>) {
+    let (size, text) = match body {
+        Some(HttpBody::Text(text)) => (text.len(), Some(text.clone())),
+        Some(HttpBody::Bytes(bytes)) => (bytes.len(), String::from_utf8(bytes.clone()).ok()),
+        _ => (0, None),
+    };
+
+    if settings.capture_request_body && size <= settings.max_body_size {
+        (size, text)
+    } else {
+        (size, None)
+    }
+}
+
+/// Pre-flight connectivity probe used when `ClientSettings::
+/// offline_detection` is set: opens (and immediately drops) a TCP
+/// connection to `url`'s host, bounded by `probe_timeout`, so a request
+/// fails instantly with `RhttpError::RhttpOffline` on a dead network
+/// instead of waiting out a full connect timeout.
+///
+/// There's no platform reachability API available to this library (it's
+/// called from Dart over FFI with no OS-level network status hook
+/// exposed), so a short connect probe is the only mechanism, on every
+/// platform. It also resolves the host through the OS resolver directly
+/// rather than any `DnsSettings` override configured on the client, so a
+/// client with custom DNS may get a different reachability signal than
+/// the request it's gating actually gets -- that's an acceptable
+/// trade-off for a lightweight pre-check.
+async fn check_reachable(url: &reqwest::Url, probe_timeout: Duration) -> Result<(), RhttpError> {
+    let host = url.host_str().ok_or_else(|| {
+        RhttpError::RhttpInvalidUrl(url.to_string(), "missing host".to_string())
+    })?;
+    let port = url.port_or_known_default().unwrap_or(443);
+
+    let probe_timeout = probe_timeout
+        .to_std()
+        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+
+    match tokio::time::timeout(probe_timeout, tokio::net::TcpStream::connect((host, port))).await {
+        Ok(Ok(_)) => Ok(()),
+        _ => Err(RhttpError::RhttpOffline),
+    }
+}
+
+/// Builds a `reqwest::Response` from a registered `MockResponse`, so mock
+/// mode flows through the same status-checking and body-decoding path in
+/// `make_http_request_helper`/`make_http_request_inner` as a real response,
+/// rather than a separate one that could drift out of sync with it.
+fn build_mock_response(mock: MockResponse, url: reqwest::Url) -> Result<Response, RhttpError> {
+    let mut builder = http::Response::builder().status(mock.status_code).url(url);
+
+    for (name, value) in mock.headers {
+        builder = builder.header(name, value);
+    }
+
+    let response = builder
+        .body(mock.body)
+        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+
+    Ok(Response::from(response))
+}
+
+/// Decodes `response`'s body based on its `Content-Type`. See
+/// `HttpExpectBody::Auto`.
+async fn decode_auto(
+    client: &RequestClient,
+    response: Response,
+    bandwidth_priority: Option<BandwidthPriority>,
+) -> Result<HttpResponseBody, RhttpError> {
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+
+    let essence = content_type.split(';').next().unwrap_or("").trim();
+    if essence == "application/json" || essence.ends_with("+json") {
+        let bytes = read_body_throttled(client, response, bandwidth_priority).await?;
+        let text =
+            String::from_utf8(bytes).map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+        serde_json::from_str::<serde_json::Value>(&text)
+            .map_err(|e| RhttpError::RhttpJsonError(e.to_string()))?;
+        Ok(HttpResponseBody::Json(text))
+    } else if content_type.starts_with("text/") {
+        let bytes = read_body_throttled(client, response, bandwidth_priority).await?;
+        Ok(HttpResponseBody::Text(String::from_utf8(bytes).map_err(
+            |e| RhttpError::RhttpUnknownError(e.to_string()),
+        )?))
+    } else {
+        Ok(HttpResponseBody::Bytes(
+            read_body_throttled(client, response, bandwidth_priority).await?,
+        ))
+    }
+}
+
+/// Maps an error surfaced while reading a response body stream, tagging a
+/// timeout as `TimeoutPhase::ReadingBody` -- unlike `map_execute_error`,
+/// this is reached only once headers have already arrived and the body
+/// itself is being read, so the phase is known for certain rather than
+/// guessed at.
+fn map_body_read_error(e: reqwest::Error) -> RhttpError {
+    if e.is_timeout() {
+        RhttpError::RhttpTimeoutError(TimeoutPhase::ReadingBody)
+    } else {
+        RhttpError::RhttpUnknownError(e.to_string())
+    }
+}
+
+/// Reads the whole response body, pacing it against `client.download_bucket`
+/// (shared with every other request this client has in flight) when that's
+/// set instead of reading it in one shot. See
+/// `ClientSettings::bandwidth_settings`.
+async fn read_body_throttled(
+    client: &RequestClient,
+    response: Response,
+    bandwidth_priority: Option<BandwidthPriority>,
+) -> Result<Vec<u8>, RhttpError> {
+    let Some(bucket) = client.download_bucket.clone() else {
+        let bytes = response.bytes().await.map_err(map_body_read_error)?;
+        client.add_bytes_transferred(bytes.len() as u64);
+        return Ok(bytes.to_vec());
+    };
+
+    let weight = bandwidth_priority
+        .unwrap_or(BandwidthPriority::Normal)
+        .weight();
+    let mut stream = response.bytes_stream();
+    let mut body = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(map_body_read_error)?;
+        bucket
+            .lock()
+            .await
+            .consume_weighted(chunk.len(), weight)
+            .await;
+        client.add_bytes_transferred(chunk.len() as u64);
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Applies `body_codec`'s `decode_chunk` to an already-fully-buffered
+/// response body, after reqwest's own transparent `Content-Encoding`
+/// decompression has run. Only used for `HttpExpectBody::Text`/`Bytes`,
+/// which buffer the whole body anyway -- streaming response paths don't
+/// currently have a codec hook, matching how `content_hash_algorithm` is
+/// also `Text`/`Bytes`-only on the request side.
+async fn decode_response_bytes(body_codec: &Option<BodyCodec>, bytes: Vec<u8>) -> Vec<u8> {
+    match body_codec {
+        Some(codec) => (codec.decode_chunk)(bytes).await,
+        None => bytes,
+    }
+}
+
+/// Reads and discards the whole response body without buffering it, so the
+/// connection it came in on can be returned to reqwest's pool. See
+/// `HttpExpectBody::Discard`. Paced against `client.download_bucket` the
+/// same way `read_body_throttled` is, since a discarded body still counts
+/// as bytes transferred for `ClientSettings::bandwidth_settings`.
+async fn drain_body(
+    client: &RequestClient,
+    response: Response,
+    bandwidth_priority: Option<BandwidthPriority>,
+) -> Result<(), RhttpError> {
+    let Some(bucket) = client.download_bucket.clone() else {
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(map_body_read_error)?;
+            client.add_bytes_transferred(chunk.len() as u64);
+        }
+        return Ok(());
+    };
+
+    let weight = bandwidth_priority
+        .unwrap_or(BandwidthPriority::Normal)
+        .weight();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(map_body_read_error)?;
+        bucket
+            .lock()
+            .await
+            .consume_weighted(chunk.len(), weight)
+            .await;
+        client.add_bytes_transferred(chunk.len() as u64);
+    }
+    Ok(())
+}
+
+pub async fn make_http_request_receive_stream(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    url: String,
+    query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
+    headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    body: Option<HttpBody>,
+    body_stream: Option<stream::Dart2RustStreamReceiver>,
+    transfer_encoding: Option<TransferEncoding>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    min_chunk_size: Option<usize>,
+    max_buffer_time: Option<Duration>,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+    demand_stream: Option<stream::Dart2RustStreamReceiver>,
+    stream_sink: StreamSink<Vec<u8>>,
+    on_response: impl Fn(HttpResponse) -> DartFnFuture<()>,
+    on_error: impl Fn(RhttpError) -> DartFnFuture<()>,
+    operation_deadline: Option<OperationDeadline>,
+    on_cancel_token: impl Fn(CancellationToken) -> DartFnFuture<()>,
+    cancelable: bool,
+) {
+    let cancel_tokens = build_cancel_tokens(client.clone());
+
+    if cancelable {
+        on_cancel_token(cancel_tokens.request_cancel_token.clone()).await;
+    }
+
+    tokio::select! {
+        _ = cancel_tokens.request_cancel_token.cancelled() => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpCancelError).await;
+        },
+        _ = cancel_tokens.client_cancel_token.cancelled() => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpCancelError).await;
+        },
+        _ = await_operation_deadline(&operation_deadline) => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpTimeoutError(TimeoutPhase::Total)).await;
+        },
+        _ = make_http_request_receive_stream_inner(
+            client,
+            settings,
+            method,
+            url.to_owned(),
+            query,
+            query_array_encoding,
+            headers,
+            remove_headers,
+            suppress_default_accept,
+            throw_on_status,
+            http_version_override,
+            body,
+            body_stream,
+            transfer_encoding,
+            client_certificate,
+            proxy_override,
+            min_chunk_size,
+            max_buffer_time,
+            tag,
+            idempotency_key,
+            if_match,
+            content_hash_algorithm,
+            trailer_checksum,
+            codec,
+            trace_context,
+            tcp_nodelay,
+            connection_lease,
+            request_compression,
+            bandwidth_priority,
+            demand_stream,
+            stream_sink.clone(),
+            on_response,
+            &on_error,
+        ) => {},
+    }
+}
+
+async fn make_http_request_receive_stream_inner(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    url: String,
+    query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
+    headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    body: Option<HttpBody>,
+    body_stream: Option<stream::Dart2RustStreamReceiver>,
+    transfer_encoding: Option<TransferEncoding>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    min_chunk_size: Option<usize>,
+    max_buffer_time: Option<Duration>,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+    demand_stream: Option<stream::Dart2RustStreamReceiver>,
+    stream_sink: StreamSink<Vec<u8>>,
+    on_response: impl Fn(HttpResponse) -> DartFnFuture<()>,
+    on_error: &impl Fn(RhttpError) -> DartFnFuture<()>,
+) {
+    if demand_stream.is_some() && min_chunk_size.is_some() {
+        on_error(RhttpError::RhttpUnsupportedError(
+            "demand_stream cannot be combined with min_chunk_size: pull-based flow control reads exactly one body chunk per demand signal, which coalescing would have to buffer across several".to_string(),
+        )).await;
+        return;
+    }
+
+    let response = make_http_request_helper(
+        client,
+        settings,
+        method,
+        url,
+        query,
+        query_array_encoding,
+        headers,
+        remove_headers,
+        suppress_default_accept,
+        throw_on_status,
+        http_version_override,
+        body,
+        body_stream,
+        transfer_encoding,
+        client_certificate,
+        proxy_override,
+        None,
+        tag,
+        idempotency_key,
+        if_match,
+        content_hash_algorithm,
+        trailer_checksum,
+        codec,
+        trace_context,
+        tcp_nodelay,
+        connection_lease,
+        request_compression,
+        bandwidth_priority,
+    )
+    .await;
+
+    let (response, client, debug_info, request_body_hash, response_source, _body_codec): (
+        Response,
+        _,
+        _,
+        _,
+        _,
+        _,
+    ) = match response {
+        Ok(res) => res,
+        Err(e) => {
+            on_error(e.clone()).await;
+            return;
+        }
+    };
+
+    let http_response = HttpResponse {
+        headers: header_to_vec(response.headers()),
+        version: HttpVersion::from_version(response.version()),
+        status_code: response.status().as_u16(),
+        body: HttpResponseBody::Stream,
+        content_length: response.content_length(),
+        alt_svc: parse_alt_svc(response.headers()),
+        suggested_filename: parse_suggested_filename(response.headers()),
+        etag: parse_etag(response.headers()),
+        trailers: Vec::new(),
+        remote_addr: response.remote_addr().map(|a| a.to_string()),
+        local_addr: None,
+        raw_request: None,
+        raw_response: None,
+        debug_info,
+        request_body_hash,
+        response_source,
+    };
+
+    on_response(http_response).await;
+
+    let mut stream: std::pin::Pin<Box<dyn futures_util::Stream<Item = _> + Send>> =
+        match client.download_bucket.clone() {
+            Some(bucket) => Box::pin(throttle_stream(
+                response.bytes_stream(),
+                bucket,
+                bandwidth_priority
+                    .unwrap_or(BandwidthPriority::Normal)
+                    .weight(),
+            )),
+            None => Box::pin(response.bytes_stream()),
+        };
+
+    let Some(min_chunk_size) = min_chunk_size else {
+        let mut demand_stream = demand_stream;
+        loop {
+            // Pull-based flow control: wait for the consumer's demand signal
+            // before reading the next chunk off the wire at all, so a slow
+            // consumer bounds how far ahead of it this stream is allowed to
+            // read -- true backpressure to the TCP layer, rather than
+            // reading eagerly and buffering on the bridge. A closed demand
+            // channel ends the stream, same as the body itself ending.
+            if let Some(demand) = demand_stream.as_mut() {
+                if demand.receiver.next().await.is_none() {
+                    return;
+                }
+            }
+
+            let Some(chunk) = stream.next().await else {
+                return;
+            };
+
+            let chunk = chunk.inspect_err(|e| {
+                let _ = stream_sink.add_error(anyhow::anyhow!(e.to_string()));
+            });
+
+            if chunk.is_err() {
+                return;
+            }
+
+            let chunk = chunk.unwrap();
+            client.add_bytes_transferred(chunk.len() as u64);
+
+            let result = stream_sink.add(chunk.to_vec()).inspect_err(|e| {
+                let _ = stream_sink.add_error(anyhow::anyhow!(e.to_string()));
+            });
+
+            if result.is_err() {
+                return;
+            }
+        }
+    };
+
+    // Coalesces chunks into `buffer` until it reaches `min_chunk_size`, so a
+    // stream of many tiny chunks doesn't pay the bridge-crossing cost for
+    // each one. `max_buffer_time` bounds how long a partial buffer can sit
+    // before being flushed anyway, so a slow trickle of bytes below the
+    // target size doesn't stall delivery indefinitely.
+    let max_buffer_time = max_buffer_time.and_then(|d| d.to_std().ok());
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut buffer_deadline: Option<tokio::time::Instant> = None;
+
+    loop {
+        let timeout = async {
+            match buffer_deadline {
+                Some(deadline) => tokio::time::sleep_until(deadline).await,
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            chunk = stream.next() => {
+                match chunk {
+                    Some(Ok(bytes)) => {
+                        if buffer.is_empty() {
+                            buffer_deadline = max_buffer_time.map(|d| tokio::time::Instant::now() + d);
+                        }
+                        client.add_bytes_transferred(bytes.len() as u64);
+                        buffer.extend_from_slice(&bytes);
+
+                        if buffer.len() >= min_chunk_size {
+                            buffer_deadline = None;
+                            if stream_sink.add(std::mem::take(&mut buffer)).inspect_err(|e| {
+                                let _ = stream_sink.add_error(anyhow::anyhow!(e.to_string()));
+                            }).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Some(Err(e)) => {
+                        let _ = stream_sink.add_error(anyhow::anyhow!(e.to_string()));
+                        return;
+                    }
+                    None => {
+                        if !buffer.is_empty() {
+                            let _ = stream_sink.add(buffer);
+                        }
+                        return;
+                    }
+                }
+            }
+            _ = timeout => {
+                buffer_deadline = None;
+                if !buffer.is_empty() && stream_sink.add(std::mem::take(&mut buffer)).inspect_err(|e| {
+                    let _ = stream_sink.add_error(anyhow::anyhow!(e.to_string()));
+                }).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Streams the response body as NDJSON (newline-delimited JSON), delivering
+/// one `NdjsonLine` per complete line as soon as it's seen, instead of the
+/// caller buffering raw bytes and splitting them by hand. A line spanning a
+/// chunk boundary is buffered until it's complete.
+///
+/// A line that isn't valid JSON is delivered as `NdjsonLine::Malformed`
+/// rather than aborting the stream, unless `fail_fast_on_malformed_line` is
+/// set, in which case it ends the stream with `RhttpError::RhttpJsonError`.
+pub async fn make_http_request_receive_ndjson(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    url: String,
+    query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
+    headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    body: Option<HttpBody>,
+    body_stream: Option<stream::Dart2RustStreamReceiver>,
+    transfer_encoding: Option<TransferEncoding>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    fail_fast_on_malformed_line: Option<bool>,
+    decompress_gzip_stream: Option<bool>,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+    stream_sink: StreamSink<NdjsonLine>,
+    on_response: impl Fn(HttpResponse) -> DartFnFuture<()>,
+    on_error: impl Fn(RhttpError) -> DartFnFuture<()>,
+    operation_deadline: Option<OperationDeadline>,
+    on_cancel_token: impl Fn(CancellationToken) -> DartFnFuture<()>,
+    cancelable: bool,
+) {
+    let cancel_tokens = build_cancel_tokens(client.clone());
+
+    if cancelable {
+        on_cancel_token(cancel_tokens.request_cancel_token.clone()).await;
+    }
+
+    tokio::select! {
+        _ = cancel_tokens.request_cancel_token.cancelled() => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpCancelError).await;
+        },
+        _ = cancel_tokens.client_cancel_token.cancelled() => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpCancelError).await;
+        },
+        _ = await_operation_deadline(&operation_deadline) => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpTimeoutError(TimeoutPhase::Total)).await;
+        },
+        _ = make_http_request_receive_ndjson_inner(
+            client,
+            settings,
+            method,
+            url.to_owned(),
+            query,
+            query_array_encoding,
+            headers,
+            remove_headers,
+            suppress_default_accept,
+            throw_on_status,
+            http_version_override,
+            body,
+            body_stream,
+            transfer_encoding,
+            client_certificate,
+            proxy_override,
+            fail_fast_on_malformed_line.unwrap_or(false),
+            decompress_gzip_stream.unwrap_or(false),
+            tag,
+            idempotency_key,
+            if_match,
+            content_hash_algorithm,
+            trailer_checksum,
+            codec,
+            trace_context,
+            tcp_nodelay,
+            connection_lease,
+            request_compression,
+            bandwidth_priority,
+            stream_sink.clone(),
+            on_response,
+            &on_error,
+        ) => {},
+    }
+}
+
+async fn make_http_request_receive_ndjson_inner(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    url: String,
+    query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
+    headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    body: Option<HttpBody>,
+    body_stream: Option<stream::Dart2RustStreamReceiver>,
+    transfer_encoding: Option<TransferEncoding>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    fail_fast_on_malformed_line: bool,
+    decompress_gzip_stream: bool,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+    stream_sink: StreamSink<NdjsonLine>,
+    on_response: impl Fn(HttpResponse) -> DartFnFuture<()>,
+    on_error: &impl Fn(RhttpError) -> DartFnFuture<()>,
+) {
+    // A `decompress_gzip_stream` request negotiates gzip itself (below) but
+    // asks reqwest not to auto-decode it, so the raw compressed bytes reach
+    // `stream` below and can be decompressed incrementally instead of all at
+    // once -- the same reason `client_for_compression` exists for per-request
+    // codec negotiation overrides.
+    let (headers, request_compression) = if decompress_gzip_stream {
+        let headers = match ensure_accept_encoding_gzip(headers) {
+            Ok(headers) => headers,
+            Err(e) => {
+                on_error(e).await;
+                return;
+            }
+        };
+        (
+            Some(headers),
+            Some(RequestCompression {
+                gzip: false,
+                brotli: request_compression.map(|c| c.brotli).unwrap_or(false),
+            }),
+        )
+    } else {
+        (headers, request_compression)
+    };
+
+    let response = make_http_request_helper(
+        client,
+        settings,
+        method,
+        url,
+        query,
+        query_array_encoding,
+        headers,
+        remove_headers,
+        suppress_default_accept,
+        throw_on_status,
+        http_version_override,
+        body,
+        body_stream,
+        transfer_encoding,
+        client_certificate,
+        proxy_override,
+        None,
+        tag,
+        idempotency_key,
+        if_match,
+        content_hash_algorithm,
+        trailer_checksum,
+        codec,
+        trace_context,
+        tcp_nodelay,
+        connection_lease,
+        request_compression,
+        bandwidth_priority,
+    )
+    .await;
+
+    let (response, client, debug_info, request_body_hash, response_source, _body_codec): (
+        Response,
+        _,
+        _,
+        _,
+        _,
+        _,
+    ) = match response {
+        Ok(res) => res,
+        Err(e) => {
+            on_error(e.clone()).await;
+            return;
+        }
+    };
+
+    let http_response = HttpResponse {
+        headers: header_to_vec(response.headers()),
+        version: HttpVersion::from_version(response.version()),
+        status_code: response.status().as_u16(),
+        body: HttpResponseBody::Stream,
+        content_length: response.content_length(),
+        alt_svc: parse_alt_svc(response.headers()),
+        suggested_filename: parse_suggested_filename(response.headers()),
+        etag: parse_etag(response.headers()),
+        trailers: Vec::new(),
+        remote_addr: response.remote_addr().map(|a| a.to_string()),
+        local_addr: None,
+        raw_request: None,
+        raw_response: None,
+        debug_info,
+        request_body_hash,
+        response_source,
+    };
+
+    // A server is free to ignore the `Accept-Encoding: gzip` negotiated
+    // above and return an uncompressed (or differently-encoded) body, so
+    // the incremental decoder below only runs when the response actually
+    // came back gzip-encoded -- otherwise the raw bytes are passed through
+    // untouched, honoring what the server actually sent instead of what
+    // was merely requested.
+    let should_decode_gzip = decompress_gzip_stream
+        && response
+            .headers()
+            .get(header::CONTENT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.eq_ignore_ascii_case("gzip"));
+
+    on_response(http_response).await;
+
+    // With `should_decode_gzip`, `response`'s body is still gzip-encoded on
+    // the wire (reqwest was told not to auto-decode it above), so it's
+    // piped through an incremental decoder here instead -- see
+    // `gzip_stream::decode_gzip_stream`.
+    let compressed_bytes = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+    let mut stream: std::pin::Pin<
+        Box<dyn futures_util::Stream<Item = Result<bytes::Bytes, String>> + Send>,
+    > = if should_decode_gzip {
+        let compressed_bytes = compressed_bytes.clone();
+        let client_for_metrics = client.clone();
+        let raw = response
+            .bytes_stream()
+            .inspect(move |chunk| {
+                if let Ok(chunk) = chunk {
+                    compressed_bytes
+                        .fetch_add(chunk.len() as u64, std::sync::atomic::Ordering::Relaxed);
+                    client_for_metrics.add_bytes_transferred(chunk.len() as u64);
+                }
+            })
+            .map(|r| r.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)));
+        Box::pin(gzip_stream::decode_gzip_stream(raw).map(|r| r.map_err(|e| e.to_string())))
+    } else {
+        Box::pin(
+            response
+                .bytes_stream()
+                .map(|r| r.map_err(|e| e.to_string())),
+        )
+    };
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut decompressed_bytes: u64 = 0;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                let _ = stream_sink.add_error(anyhow::anyhow!(e));
+                return;
+            }
+        };
+
+        if should_decode_gzip {
+            decompressed_bytes += chunk.len() as u64;
+            if let Some(max_ratio) = client.settings.max_decompression_ratio {
+                let compressed = compressed_bytes.load(std::sync::atomic::Ordering::Relaxed);
+                if decompression_guard::exceeds_ratio(compressed, decompressed_bytes, max_ratio) {
+                    let _ = stream_sink.add_error(anyhow::anyhow!(
+                        RhttpError::RhttpDecompressionBomb.to_string()
+                    ));
+                    return;
+                }
+            }
+        } else {
+            client.add_bytes_transferred(chunk.len() as u64);
+        }
+
+        for line in ndjson::drain_ndjson_lines(&mut buffer, &chunk) {
+            if let NdjsonLine::Malformed(text, reason) = &line {
+                if fail_fast_on_malformed_line {
+                    on_error(RhttpError::RhttpJsonError(format!(
+                        "malformed NDJSON line: {reason} ({text})"
+                    )))
+                    .await;
+                    return;
+                }
+            }
+            if stream_sink.add(line).is_err() {
+                return;
+            }
+        }
+    }
+
+    if let Some(line) = ndjson::finish_ndjson_buffer(&buffer) {
+        if let NdjsonLine::Malformed(text, reason) = &line {
+            if fail_fast_on_malformed_line {
+                on_error(RhttpError::RhttpJsonError(format!(
+                    "malformed NDJSON line: {reason} ({text})"
+                )))
+                .await;
+                return;
+            }
+        }
+        let _ = stream_sink.add(line);
+    }
+}
+
+/// Streams a `multipart/mixed` or `multipart/x-mixed-replace` (MJPEG-style)
+/// response body, parsing it by boundary and delivering each part's headers
+/// and body as soon as it arrives, instead of the caller buffering the
+/// whole response and splitting it by hand. The boundary is read from the
+/// response's own `Content-Type` header; a response whose `Content-Type`
+/// has no `boundary` parameter fails with `RhttpError::RhttpUnsupportedError`.
+pub async fn make_http_request_receive_multipart(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    url: String,
+    query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
+    headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    body: Option<HttpBody>,
+    body_stream: Option<stream::Dart2RustStreamReceiver>,
+    transfer_encoding: Option<TransferEncoding>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+    stream_sink: StreamSink<MultipartPart>,
+    on_response: impl Fn(HttpResponse) -> DartFnFuture<()>,
+    on_error: impl Fn(RhttpError) -> DartFnFuture<()>,
+    operation_deadline: Option<OperationDeadline>,
+    on_cancel_token: impl Fn(CancellationToken) -> DartFnFuture<()>,
+    cancelable: bool,
+) {
+    let cancel_tokens = build_cancel_tokens(client.clone());
+
+    if cancelable {
+        on_cancel_token(cancel_tokens.request_cancel_token.clone()).await;
+    }
+
+    tokio::select! {
+        _ = cancel_tokens.request_cancel_token.cancelled() => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpCancelError).await;
+        },
+        _ = cancel_tokens.client_cancel_token.cancelled() => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpCancelError).await;
+        },
+        _ = await_operation_deadline(&operation_deadline) => {
+            let _ = stream_sink.add_error(anyhow::anyhow!(error::STREAM_CANCEL_ERROR));
+            on_error(RhttpError::RhttpTimeoutError(TimeoutPhase::Total)).await;
+        },
+        _ = make_http_request_receive_multipart_inner(
+            client,
+            settings,
+            method,
+            url.to_owned(),
+            query,
+            query_array_encoding,
+            headers,
+            remove_headers,
+            suppress_default_accept,
+            throw_on_status,
+            http_version_override,
+            body,
+            body_stream,
+            transfer_encoding,
+            client_certificate,
+            proxy_override,
+            tag,
+            idempotency_key,
+            if_match,
+            content_hash_algorithm,
+            trailer_checksum,
+            codec,
+            trace_context,
+            tcp_nodelay,
+            connection_lease,
+            request_compression,
+            bandwidth_priority,
+            stream_sink.clone(),
+            on_response,
+            &on_error,
+        ) => {},
+    }
+}
+
+async fn make_http_request_receive_multipart_inner(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    url: String,
+    query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
+    headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    body: Option<HttpBody>,
+    body_stream: Option<stream::Dart2RustStreamReceiver>,
+    transfer_encoding: Option<TransferEncoding>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+    stream_sink: StreamSink<MultipartPart>,
+    on_response: impl Fn(HttpResponse) -> DartFnFuture<()>,
+    on_error: &impl Fn(RhttpError) -> DartFnFuture<()>,
+) {
+    let response = make_http_request_helper(
+        client,
+        settings,
+        method,
+        url,
+        query,
+        query_array_encoding,
+        headers,
+        remove_headers,
+        suppress_default_accept,
+        throw_on_status,
+        http_version_override,
+        body,
+        body_stream,
+        transfer_encoding,
+        client_certificate,
+        proxy_override,
+        None,
+        tag,
+        idempotency_key,
+        if_match,
+        content_hash_algorithm,
+        trailer_checksum,
+        codec,
+        trace_context,
+        tcp_nodelay,
+        connection_lease,
+        request_compression,
+        bandwidth_priority,
+    )
+    .await;
+
+    let (response, client, debug_info, request_body_hash, response_source, _body_codec): (
+        Response,
+        _,
+        _,
+        _,
+        _,
+        _,
+    ) = match response {
+        Ok(res) => res,
+        Err(e) => {
+            on_error(e.clone()).await;
+            return;
+        }
+    };
+
+    let boundary = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(multipart_stream::parse_boundary);
+
+    let boundary = match boundary {
+        Some(boundary) => boundary,
+        None => {
+            on_error(RhttpError::RhttpUnsupportedError(
+                "response Content-Type has no multipart boundary parameter".to_string(),
+            ))
+            .await;
+            return;
+        }
+    };
+
+    let http_response = HttpResponse {
+        headers: header_to_vec(response.headers()),
+        version: HttpVersion::from_version(response.version()),
+        status_code: response.status().as_u16(),
+        body: HttpResponseBody::Stream,
+        content_length: response.content_length(),
+        alt_svc: parse_alt_svc(response.headers()),
+        suggested_filename: parse_suggested_filename(response.headers()),
+        etag: parse_etag(response.headers()),
+        trailers: Vec::new(),
+        remote_addr: response.remote_addr().map(|a| a.to_string()),
+        local_addr: None,
+        raw_request: None,
+        raw_response: None,
+        debug_info,
+        request_body_hash,
+        response_source,
+    };
+
+    on_response(http_response).await;
+
+    let mut parser = multipart_stream::MultipartParser::new(&boundary);
+    let mut stream = response.bytes_stream();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                let _ = stream_sink.add_error(anyhow::anyhow!(e.to_string()));
+                return;
+            }
+        };
+        client.add_bytes_transferred(chunk.len() as u64);
+
+        for part in parser.feed(&chunk) {
+            if stream_sink.add(part).is_err() {
+                return;
+            }
+        }
+
+        if parser.finished() {
+            return;
+        }
+    }
+}
+
+/// Opens a CONNECT tunnel through the client's configured proxy to
+/// `target_host:target_port`, for tunneling an arbitrary (non-HTTP)
+/// protocol rather than TLS-over-HTTPS. The client's cancel token would
+/// close the tunnel once established.
+///
+/// Not yet wired: reqwest establishes CONNECT tunnels internally (to run
+/// TLS through an HTTP proxy) via hyper, but never exposes the resulting
+/// connection back to the caller -- there's no public API to hand back a
+/// raw bidirectional stream once the tunnel is up. Supporting this would
+/// mean reimplementing HTTP proxying below reqwest, which is out of
+/// scope for this library. Always fails with
+/// `RhttpError::RhttpUnsupportedError`.
+pub async fn open_tunnel(
+    client: RustAutoOpaque<RequestClient>,
+    target_host: String,
+    target_port: u16,
+    stream_sink: StreamSink<Vec<u8>>,
+) -> Result<(), RhttpError> {
+    let _ = (client, target_host, target_port, stream_sink);
+    Err(RhttpError::RhttpUnsupportedError(
+        "CONNECT tunnels are not supported: reqwest doesn't expose the underlying connection after establishing one".to_string(),
+    ))
+}
+
+/// Which upgrade mechanism to use when opening a WebSocket. See
+/// `open_websocket`.
+#[derive(Clone, Copy)]
+pub enum WebSocketVersionPreference {
+    /// Extended CONNECT (RFC 8441) on h2, falling back to the HTTP/1.1
+    /// `Upgrade` handshake when the connection didn't negotiate h2.
+    Auto,
+    Http1,
+    Http2,
+}
+
+/// Opens a WebSocket connection to `url`, reusing the client's TLS and
+/// proxy configuration. `version` selects between the HTTP/1.1 `Upgrade`
+/// handshake and HTTP/2 extended CONNECT (RFC 8441); `Auto` picks based on
+/// the protocol the underlying connection negotiates.
+///
+/// Not yet wired: this library has no WebSocket transport of its own to
+/// extend -- it only sends the `Sec-WebSocket-*` headers as ordinary
+/// request headers, and reqwest itself has no WebSocket support (it isn't
+/// built on hyper's upgrade API at all; `Client::execute` always reads the
+/// response as a body). Extended CONNECT specifically would additionally
+/// need `h2`'s extended-CONNECT support surfaced through reqwest, which
+/// isn't exposed either. Always fails with
+/// `RhttpError::RhttpUnsupportedError`.
+pub async fn open_websocket(
+    client: RustAutoOpaque<RequestClient>,
+    url: String,
+    version: Option<WebSocketVersionPreference>,
+    stream_sink: StreamSink<Vec<u8>>,
+) -> Result<(), RhttpError> {
+    let _ = (client, url, version, stream_sink);
+    Err(RhttpError::RhttpUnsupportedError(
+        "WebSockets are not supported: reqwest has no WebSocket transport (HTTP/1.1 Upgrade or HTTP/2 extended CONNECT) to build one on".to_string(),
+    ))
+}
+
+/// Streams a response body directly to a file at `destination_path`,
+/// without buffering the whole response in memory.
+///
+/// The body is first written to a temporary file under `temp_dir`
+/// (falling back to the platform temp directory when unset), then
+/// renamed into place once the transfer completes, so a reader never
+/// observes a partial file at `destination_path`. The temp file is
+/// removed if the request or write fails, and `temp_dir` is checked for
+/// writability up front so a bad path fails before the request is sent.
+///
+/// `unix_file_mode` sets the permission bits (e.g. `0o600`) applied to the
+/// temp file before it's renamed into place, so `destination_path` never
+/// exists with the platform-default mode even briefly; ignored on
+/// non-unix platforms.
+pub async fn download_to_file(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    url: String,
+    headers: Option<HttpHeaders>,
+    destination_path: String,
+    temp_dir: Option<String>,
+    unix_file_mode: Option<u32>,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+) -> Result<(), RhttpError> {
+    let temp_dir = match temp_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => std::env::temp_dir(),
+    };
+
+    let suffix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let temp_path = temp_dir.join(format!(".rhttp-download-{suffix}"));
+
+    let file = tokio::fs::File::create(&temp_path)
+        .await
+        .map_err(|e| RhttpError::RhttpUnknownError(format!("temp_dir is not writable: {e}")))?;
+
+    #[cfg(unix)]
+    if let Some(mode) = unix_file_mode {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(mode))
+            .await
+            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+    }
+    #[cfg(not(unix))]
+    let _ = unix_file_mode;
+
+    let mut file = file;
+
+    let result: Result<(), RhttpError> = async {
+        let (response, client, _debug_info, _request_body_hash, _response_source, _body_codec) =
+            make_http_request_helper(
+                client,
+                settings,
+                method,
+                url,
+                None,
+                None,
+                headers,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                tag,
+                idempotency_key,
+                if_match,
+                content_hash_algorithm,
+                trailer_checksum,
+                codec,
+                trace_context,
+                tcp_nodelay,
+                connection_lease,
+                request_compression,
+                bandwidth_priority,
+            )
+            .await?;
+
+        let mut stream: std::pin::Pin<Box<dyn futures_util::Stream<Item = _> + Send>> =
+            match client.download_bucket.clone() {
+                Some(bucket) => Box::pin(throttle_stream(
+                    response.bytes_stream(),
+                    bucket,
+                    bandwidth_priority
+                        .unwrap_or(BandwidthPriority::Normal)
+                        .weight(),
+                )),
+                None => Box::pin(response.bytes_stream()),
+            };
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+            client.add_bytes_transferred(chunk.len() as u64);
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+        }
+
+        file.flush()
+            .await
+            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))
+    }
+    .await;
+
+    match result {
+        Ok(()) => tokio::fs::rename(&temp_path, &destination_path)
+            .await
+            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string())),
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            Err(e)
+        }
+    }
+}
+
+/// Relays a GET response's body directly into another request's body, for
+/// piping a large object from one URL to another (e.g. downloading from one
+/// service and re-uploading it to another) without ever buffering the whole
+/// thing in memory.
+///
+/// The source response is fetched via `make_http_request_helper` exactly
+/// like `download_to_file`, but instead of writing it to a file, its bytes
+/// are forwarded chunk-by-chunk into a fresh `stream::Dart2RustStreamReceiver`
+/// so the destination request can be sent through the ordinary
+/// `HttpBody::BytesStream` path -- which already provides backpressure (the
+/// channel has a bounded buffer), the client's own upload throttling, and
+/// HTTP/1.0 buffering -- rather than this function reimplementing any of
+/// that. Cancelling either the source or the destination client tears down
+/// the whole pipe; if the source stream errors partway through, the
+/// destination sees a truncated body rather than the source's error, since
+/// `HttpBody::BytesStream`'s channel has no way to carry it across.
+#[allow(clippy::too_many_arguments)]
+pub async fn relay_request(
+    source_client: Option<RustAutoOpaque<RequestClient>>,
+    source_settings: Option<ClientSettings>,
+    source_url: String,
+    source_headers: Option<HttpHeaders>,
+    destination_client: Option<RustAutoOpaque<RequestClient>>,
+    destination_settings: Option<ClientSettings>,
+    destination_method: HttpMethod,
+    destination_url: String,
+    destination_headers: Option<HttpHeaders>,
+    expect_body: HttpExpectBody,
+    tag: Option<String>,
+    bandwidth_priority: Option<BandwidthPriority>,
+    forwarded_for: Option<ForwardedFor>,
+    operation_deadline: Option<OperationDeadline>,
+    on_cancel_token: impl Fn(CancellationToken) -> DartFnFuture<()>,
+    cancelable: bool,
+) -> Result<HttpResponse, RhttpError> {
+    let source_cancel_tokens = build_cancel_tokens(source_client.clone());
+    let destination_cancel_tokens = build_cancel_tokens(destination_client.clone());
+
+    if cancelable {
+        on_cancel_token(source_cancel_tokens.request_cancel_token.clone()).await;
+    }
+
+    tokio::select! {
+        _ = source_cancel_tokens.request_cancel_token.cancelled() => Err(RhttpError::RhttpCancelError),
+        _ = source_cancel_tokens.client_cancel_token.cancelled() => Err(RhttpError::RhttpCancelError),
+        _ = destination_cancel_tokens.client_cancel_token.cancelled() => Err(RhttpError::RhttpCancelError),
+        _ = await_operation_deadline(&operation_deadline) => {
+            Err(RhttpError::RhttpTimeoutError(TimeoutPhase::Total))
+        },
+        response = relay_request_inner(
+            source_client,
+            source_settings,
+            source_url,
+            source_headers,
+            destination_client,
+            destination_settings,
+            destination_method,
+            destination_url,
+            destination_headers,
+            expect_body,
+            tag,
+            bandwidth_priority,
+            forwarded_for,
+        ) => response,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn relay_request_inner(
+    source_client: Option<RustAutoOpaque<RequestClient>>,
+    source_settings: Option<ClientSettings>,
+    source_url: String,
+    source_headers: Option<HttpHeaders>,
+    destination_client: Option<RustAutoOpaque<RequestClient>>,
+    destination_settings: Option<ClientSettings>,
+    destination_method: HttpMethod,
+    destination_url: String,
+    destination_headers: Option<HttpHeaders>,
+    expect_body: HttpExpectBody,
+    tag: Option<String>,
+    bandwidth_priority: Option<BandwidthPriority>,
+    forwarded_for: Option<ForwardedFor>,
+) -> Result<HttpResponse, RhttpError> {
+    let (response, source, _debug_info, _request_body_hash, _response_source, _body_codec) =
+        make_http_request_helper(
+            source_client,
+            source_settings,
+            HttpMethod {
+                method: "GET".to_string(),
+            },
+            source_url,
+            None,
+            None,
+            source_headers,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            tag.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            bandwidth_priority,
+        )
+        .await?;
+
+    let (mut sink, receiver) = stream::create_stream();
+    let weight = bandwidth_priority
+        .unwrap_or(BandwidthPriority::Normal)
+        .weight();
+    let download_bucket = source.download_bucket.clone();
+
+    tokio::spawn(async move {
+        let mut stream: std::pin::Pin<Box<dyn futures_util::Stream<Item = _> + Send>> =
+            match download_bucket {
+                Some(bucket) => Box::pin(throttle_stream(response.bytes_stream(), bucket, weight)),
+                None => Box::pin(response.bytes_stream()),
+            };
+        while let Some(chunk) = stream.next().await {
+            let Ok(chunk) = chunk else {
+                break;
+            };
+            source.add_bytes_transferred(chunk.len() as u64);
+            if sink.add(chunk.to_vec()).await.is_err() {
+                break;
+            }
+        }
+        let _ = sink.close().await;
+    });
+
+    let destination_headers = match forwarded_for {
+        Some(forwarded_for) => Some(apply_forwarded_headers(
+            destination_headers,
+            &forwarded_for,
+        )?),
+        None => destination_headers,
+    };
+
+    make_http_request_inner(
+        destination_client,
+        destination_settings,
+        destination_method,
+        destination_url,
+        None,
+        None,
+        destination_headers,
+        None,
+        None,
+        None,
+        None,
+        Some(HttpBody::BytesStream),
+        Some(receiver),
+        None,
+        None,
+        None,
+        expect_body,
+        tag,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        bandwidth_priority,
+    )
+    .await
+}
+
+/// This function is used to make an HTTP request without any response handling.
+async fn make_http_request_helper(
+    client: Option<RustAutoOpaque<RequestClient>>,
+    settings: Option<ClientSettings>,
+    method: HttpMethod,
+    url: String,
+    query: Option<Vec<(String, String)>>,
+    query_array_encoding: Option<QueryArrayEncoding>,
+    headers: Option<HttpHeaders>,
+    remove_headers: Option<Vec<String>>,
+    suppress_default_accept: Option<bool>,
+    throw_on_status: Option<bool>,
+    http_version_override: Option<HttpVersionPref>,
+    body: Option<HttpBody>,
+    body_stream: Option<stream::Dart2RustStreamReceiver>,
+    transfer_encoding: Option<TransferEncoding>,
+    client_certificate: Option<ClientCertificate>,
+    proxy_override: Option<CustomProxy>,
+    expect_body: Option<HttpExpectBody>,
+    tag: Option<String>,
+    idempotency_key: Option<String>,
+    if_match: Option<String>,
+    content_hash_algorithm: Option<ContentHashAlgorithm>,
+    trailer_checksum: Option<TrailerChecksumAlgorithm>,
+    codec: Option<String>,
+    trace_context: Option<TraceContext>,
+    tcp_nodelay: Option<bool>,
+    connection_lease: Option<ConnectionLease>,
+    request_compression: Option<RequestCompression>,
+    bandwidth_priority: Option<BandwidthPriority>,
+) -> Result<
+    (
+        Response,
+        RequestClient,
+        Option<RequestDebugInfo>,
+        Option<String>,
+        ResponseSource,
+        Option<BodyCodec>,
+    ),
+    RhttpError,
+> {
+    let client: RequestClient = match client {
+        Some(client) => client.try_read().unwrap().clone(),
+        None => match settings {
+            Some(settings) => RequestClient::new(settings)
+                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
+            None => RequestClient::new_default(),
+        },
+    };
+    client.check_byte_quota()?;
+
+    let har_settings = client.har_recording_settings();
+    let (har_request_body_size, har_request_body_text) = match &har_settings {
+        Some(settings) => preview_request_body(&body, settings),
+        None => (0, None),
+    };
+
+    let request_body_hash = content_hash_algorithm.and_then(|algorithm| {
+        let bytes = match &body {
+            Some(HttpBody::Text(text)) => text.as_bytes(),
+            Some(HttpBody::Bytes(bytes)) => bytes.as_slice(),
+            _ => return None,
+        };
+        content_hash::hex_digest(algorithm, bytes)
+    });
+
+    if let Some(algorithm) = trailer_checksum {
+        if !matches!(body, Some(HttpBody::BytesStream)) {
+            return Err(RhttpError::RhttpUnsupportedError(
+                "trailer_checksum is only supported for HttpBody::BytesStream".to_string(),
+            ));
+        }
+        if matches!(algorithm, TrailerChecksumAlgorithm::Sha256) {
+            return Err(RhttpError::RhttpUnsupportedError(
+                "TrailerChecksumAlgorithm::Sha256 is not supported: no SHA-256 implementation is currently a dependency of this crate".to_string(),
+            ));
+        }
+    }
+
+    // Resolved once up front so a typo'd `codec` name fails immediately,
+    // rather than partway through sending the body.
+    let body_codec: Option<BodyCodec> = match codec {
+        Some(name) => Some(client.resolve_codec(&name).ok_or_else(|| {
+            RhttpError::RhttpUnknownError(format!("no codec registered named {name:?}"))
+        })?),
+        None => None,
+    };
+
+    // A codec transforms a whole body at once, mirroring how
+    // `content_hash_algorithm` is also `Text`/`Bytes`-only: `BytesStream`'s
+    // chunks are handed off to reqwest as they arrive, with no point where a
+    // "whole body" exists to run a transform against.
+    if body_codec.is_some() && matches!(body, Some(HttpBody::BytesStream)) {
+        return Err(RhttpError::RhttpUnsupportedError(
+            "codec is only supported for HttpBody::Text and HttpBody::Bytes".to_string(),
+        ));
+    }
+
+    let mut parsed_url = url::parse(&url)?;
+
+    if client.settings.require_https && parsed_url.scheme() != "https" {
+        return Err(RhttpError::RhttpInsecureScheme(parsed_url.to_string()));
+    }
+
+    if let Some(probe_timeout) = client.settings.offline_detection {
+        check_reachable(&parsed_url, probe_timeout).await?;
+    }
+
+    let (request, replay_body) = {
+        if let Some(query) = query {
+            query::append_query(
+                &mut parsed_url,
+                &query,
+                query_array_encoding.unwrap_or(QueryArrayEncoding::Repeat),
+            );
+        }
+
+        // Not yet wired: reqwest's own default `Accept: */*` lives in the
+        // same internal header map as `ClientBuilder::default_headers` and
+        // is merged into the request inside `Client::execute_request`,
+        // which runs after this function hands the request off to
+        // `execute()`. There's no hook in reqwest's public API to skip
+        // that merge, so this can't currently suppress the header -- it
+        // can only be overridden via `headers`, which is a different
+        // thing than omitting it.
+        if suppress_default_accept.unwrap_or(false) {
+            return Err(RhttpError::RhttpUnsupportedError(
+                "suppressing the default Accept header is not supported: reqwest merges it into the request internally, after this library can intercept it".to_string(),
+            ));
+        }
+
+        let mut request = client.client.request(method.to_method(), parsed_url);
+
+        let resolved_http_version = http_version_override.unwrap_or(client.http_version_pref);
+
+        // A per-request override only constrains which version this one
+        // request negotiates; it doesn't change the client's own
+        // preference for later requests. Forcing a version the client
+        // wasn't otherwise using (e.g. h1 on an h2 client) may open a
+        // separate connection rather than reusing the pool.
+        //
+        // Note this only sets the version marker on the outgoing
+        // `http::Request`; hyper's HTTP/1 codec always writes `HTTP/1.1` as
+        // the literal request-line version token regardless of it -- that
+        // part of HTTP/1.0 semantics isn't configurable through reqwest's
+        // public API. What we *can* control (below) is avoiding chunked
+        // transfer encoding and defaulting to `Connection: close`, which is
+        // what actually matters for interop with a server that can't parse
+        // chunked bodies.
+        request = match resolved_http_version {
+            HttpVersionPref::Http10 => request.version(Version::HTTP_10),
+            HttpVersionPref::Http11 => request.version(Version::HTTP_11),
+            HttpVersionPref::Http2 => request.version(Version::HTTP_2),
+            HttpVersionPref::Http3 => request.version(Version::HTTP_3),
+            HttpVersionPref::All => request,
+        };
+
+        match headers {
+            Some(HttpHeaders::Map(map)) => {
+                for (k, v) in map {
+                    let header_name = HeaderName::from_str(&k)
+                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+                    let header_value = HeaderValue::from_str(&v)
+                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+                    request = request.header(header_name, header_value);
+                }
+            }
+            Some(HttpHeaders::List(list)) => {
+                for (k, v) in list {
+                    let header_name = HeaderName::from_str(&k)
+                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+                    let header_value = HeaderValue::from_str(&v)
+                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+                    request = request.header(header_name, header_value);
+                }
+            }
+            Some(HttpHeaders::Raw(_)) => {
+                return Err(RhttpError::RhttpUnsupportedError(
+                    "raw header blocks are not supported: reqwest normalizes and validates headers through http::HeaderMap before sending".to_string(),
+                ));
+            }
+            None => (),
+        };
+
+        // Applied after `headers` so it wins if both set an `Idempotency-Key`.
+        // Threaded through as a plain parameter rather than generated here,
+        // so `execute_preserving_method`'s redirect retries and
+        // `retry_after_refresh`'s 401 retry -- which both resend a clone of
+        // this same built request -- reuse the exact same value rather than
+        // this function being asked to invent a fresh one per attempt.
+        if let Some(idempotency_key) = idempotency_key {
+            let header_value = HeaderValue::from_str(&idempotency_key)
+                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+            request = request.header("Idempotency-Key", header_value);
+        }
+
+        // `if_match` stays borrowed here (not moved) because the
+        // `throw_on_status` check further down needs to know whether it was
+        // set, to let a `412 Precondition Failed` response through as a
+        // normal, catchable `HttpResponse` instead of throwing.
+        if let Some(if_match) = if_match.as_deref() {
+            let header_value = HeaderValue::from_str(if_match)
+                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+            request = request.header(header::IF_MATCH, header_value);
+        }
+
+        let upload_bucket = client.upload_bucket.clone();
+        let bandwidth_weight = bandwidth_priority
+            .unwrap_or(BandwidthPriority::Normal)
+            .weight();
+
+        // HTTP/1.0 has no chunked transfer encoding and no trailers, so a
+        // request targeting it always needs a known Content-Length up
+        // front, and can't carry a trailer checksum at all.
+        let is_http10 = matches!(resolved_http_version, HttpVersionPref::Http10);
+
+        if is_http10 && trailer_checksum.is_some() {
+            return Err(RhttpError::RhttpUnsupportedError(
+                "trailer_checksum is not supported over HTTP/1.0: HTTP/1.0 has no trailers"
+                    .to_string(),
+            ));
+        }
+
+        // See `ClientSettings::body_replay_threshold_bytes`. Captured from
+        // `body` before it's moved into the match below, and only for the
+        // two already-fully-buffered variants -- `HttpBody::BytesStream`
+        // opts out of replay by construction.
+        let body_replay_threshold = client
+            .settings
+            .body_replay_threshold_bytes
+            .unwrap_or(DEFAULT_BODY_REPLAY_THRESHOLD_BYTES);
+        let replay_body: Option<Vec<u8>> = match &body {
+            Some(HttpBody::Text(text)) if text.len() as u64 <= body_replay_threshold => {
+                Some(text.clone().into_bytes())
+            }
+            Some(HttpBody::Bytes(bytes)) if bytes.len() as u64 <= body_replay_threshold => {
+                Some(bytes.clone())
+            }
+            _ => None,
+        };
+
+        request = match body {
+            Some(HttpBody::Text(text)) => {
+                let bytes = match &body_codec {
+                    Some(codec) => (codec.encode_chunk)(text.into_bytes()).await,
+                    None => text.into_bytes(),
+                };
+                client.add_bytes_transferred(bytes.len() as u64);
+                if let Some(bucket) = upload_bucket.clone().filter(|_| !is_http10) {
+                    request.body(throttled_body(bytes, bucket, bandwidth_weight))
+                } else if !is_http10 && matches!(transfer_encoding, Some(TransferEncoding::Chunked))
+                {
+                    request.body(chunked_body(bytes))
+                } else {
+                    request.body(bytes)
+                }
+            }
+            Some(HttpBody::Bytes(bytes)) => {
+                let bytes = match &body_codec {
+                    Some(codec) => (codec.encode_chunk)(bytes).await,
+                    None => bytes,
+                };
+                client.add_bytes_transferred(bytes.len() as u64);
+                if let Some(bucket) = upload_bucket.clone().filter(|_| !is_http10) {
+                    request.body(throttled_body(bytes, bucket, bandwidth_weight))
+                } else if !is_http10 && matches!(transfer_encoding, Some(TransferEncoding::Chunked))
+                {
+                    request.body(chunked_body(bytes))
+                } else {
+                    request.body(bytes)
+                }
+            }
+            Some(HttpBody::BytesStream) => {
+                let stream = body_stream
+                    .expect("body_stream should exist for HttpBody::BytesStream")
+                    .receiver
+                    .map(|v| Ok::<Vec<u8>, RhttpError>(v));
+
+                let body = if is_http10 {
+                    // Buffer the whole stream so it can be sent with a
+                    // known Content-Length instead of chunked encoding.
+                    let mut buffered = Vec::new();
+                    let mut stream = std::pin::pin!(stream);
+                    while let Some(chunk) = stream.next().await {
+                        buffered.extend(chunk?);
+                    }
+                    reqwest::Body::from(buffered)
+                } else {
+                    match (upload_bucket.clone(), trailer_checksum) {
+                        (Some(bucket), Some(TrailerChecksumAlgorithm::Crc32)) => {
+                            checksum_trailer_body(throttle_stream(stream, bucket, bandwidth_weight))
+                        }
+                        (Some(bucket), _) => reqwest::Body::wrap_stream(throttle_stream(
+                            stream,
+                            bucket,
+                            bandwidth_weight,
+                        )),
+                        (None, Some(TrailerChecksumAlgorithm::Crc32)) => {
+                            checksum_trailer_body(stream)
+                        }
+                        (None, _) => reqwest::Body::wrap_stream(stream),
+                    }
+                };
+                request.body(body)
+            }
+            Some(HttpBody::JsonArrayStream) => {
+                let stream = body_stream
+                    .expect("body_stream should exist for HttpBody::JsonArrayStream")
+                    .receiver
+                    .map(|v| Ok::<Vec<u8>, RhttpError>(v));
+
+                let body = if is_http10 {
+                    // Buffer the whole stream so it can be joined into one
+                    // `[elem,elem,...]` blob sent with a known
+                    // Content-Length instead of chunked encoding.
+                    let mut elements = Vec::new();
+                    let mut stream = std::pin::pin!(stream);
+                    while let Some(chunk) = stream.next().await {
+                        elements.push(chunk?);
+                    }
+                    reqwest::Body::from(json_array_bytes(elements))
+                } else {
+                    let framed = json_array_stream_body(stream);
+                    match upload_bucket.clone() {
+                        Some(bucket) => reqwest::Body::wrap_stream(throttle_stream(
+                            framed,
+                            bucket,
+                            bandwidth_weight,
+                        )),
+                        None => reqwest::Body::wrap_stream(framed),
+                    }
+                };
+                request.body(body)
+            }
+            Some(HttpBody::Form(form)) => request.form(&form),
+            Some(HttpBody::Multipart(body)) => {
+                let mut form = reqwest::multipart::Form::new();
+                for (k, v) in body.parts {
+                    let mut part = match v.value {
+                        MultipartValue::Text(text) => reqwest::multipart::Part::text(text),
+                        MultipartValue::Bytes(bytes) => reqwest::multipart::Part::bytes(bytes),
+                        MultipartValue::File(file) => {
+                            let file = tokio::fs::File::open(file).await.map_err(|_| {
+                                RhttpError::RhttpUnknownError("Failed to open file".to_string())
+                            })?;
+                            reqwest::multipart::Part::stream(reqwest::Body::wrap_stream(
+                                tokio_util::io::ReaderStream::new(file),
+                            ))
+                        }
+                    };
+
+                    if let Some(file_name) = v.file_name {
+                        part = part.file_name(file_name);
+                    }
+
+                    if let Some(content_type) = v.content_type {
+                        part = part
+                            .mime_str(&content_type)
                             .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
                     }
 
@@ -442,39 +2924,244 @@ async fn make_http_request_helper(
             None => request,
         };
 
-        request
+        let mut request = request
             .build()
-            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?
-    };
+            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
 
-    let response = client.client.execute(request).await.map_err(|e| {
-        if e.is_redirect() {
-            RhttpError::RhttpRedirectError
-        } else if e.is_timeout() {
-            RhttpError::RhttpTimeoutError
-        } else {
-            // We use the debug string because it contains more information
-            let inner = e.source();
-            let is_cert_error = match inner {
-                // TODO: This is a hacky way to check if the error is a certificate error
-                Some(inner) => format!("{:?}", inner).contains("InvalidCertificate"),
-                None => false,
+        // Merged manually, rather than via `ClientBuilder::default_headers`,
+        // so that `remove_headers` below can still drop a client default.
+        for (k, v) in &client.default_headers {
+            let header_name = HeaderName::from_str(k)
+                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+            if let header::Entry::Vacant(entry) = request.headers_mut().entry(header_name) {
+                let header_value = HeaderValue::from_str(v)
+                    .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+                entry.insert(header_value);
+            }
+        }
+
+        // HTTP/1.0 has no persistent-connection default, unlike HTTP/1.1's
+        // implicit keep-alive; only set it if the caller hasn't already
+        // supplied their own `Connection` header.
+        if is_http10 {
+            if let header::Entry::Vacant(entry) = request.headers_mut().entry(header::CONNECTION) {
+                entry.insert(HeaderValue::from_static("close"));
+            }
+        }
+
+        // Applied last so it can strip both explicitly-set headers and
+        // client defaults (e.g. a default `Authorization`) merged in above.
+        for name in remove_headers.unwrap_or_default() {
+            let header_name = HeaderName::from_str(&name)
+                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+            request.headers_mut().remove(header_name);
+        }
+
+        if let Some(trace_context) = &trace_context {
+            if let Some(on_generate_span_id) = &client.settings.on_generate_span_id {
+                let span_id = on_generate_span_id().await;
+                let traceparent = trace_context::format_traceparent(
+                    &trace_context.trace_id,
+                    &span_id,
+                    trace_context.sampled,
+                );
+                request.headers_mut().insert(
+                    HeaderName::from_static("traceparent"),
+                    HeaderValue::from_str(&traceparent)
+                        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
+                );
+                if let Some(trace_state) = &trace_context.trace_state {
+                    request.headers_mut().insert(
+                        HeaderName::from_static("tracestate"),
+                        HeaderValue::from_str(trace_state)
+                            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
+                    );
+                }
+            }
+        }
+
+        if let Some(on_sign) = &client.settings.on_sign {
+            let sign_request = SignRequest {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                headers: header_to_vec(request.headers()),
+                body: request
+                    .body()
+                    .and_then(|b| b.as_bytes())
+                    .map(|b| b.to_vec())
+                    .unwrap_or_default(),
             };
+            for (k, v) in on_sign(sign_request).await {
+                let header_name = HeaderName::from_str(&k)
+                    .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+                let header_value = HeaderValue::from_str(&v)
+                    .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+                request.headers_mut().insert(header_name, header_value);
+            }
+        }
 
-            if is_cert_error {
-                RhttpError::RhttpInvalidCertificateError(format!("{:?}", inner.unwrap()))
-            } else if e.is_connect() {
-                RhttpError::RhttpConnectionError(format!("{:?}", inner.unwrap()))
-            } else {
-                RhttpError::RhttpUnknownError(match inner {
-                    Some(inner) => format!("{inner:?}"),
-                    None => format!("{e:?}"),
-                })
+        (request, replay_body)
+    };
+
+    // Captured after the request is fully built (so a recorded entry
+    // reflects the final method/URL/headers, including query params and
+    // client defaults) but before it's handed to a client, since sending
+    // consumes `request`.
+    let har_capture = har_settings.map(|settings| HarCapture {
+        started_date_time: chrono::Utc::now().to_rfc3339(),
+        start: std::time::Instant::now(),
+        method: request.method().to_string(),
+        url: request.url().to_string(),
+        request_headers: har::redact_headers(
+            &header_to_vec(request.headers()),
+            &settings.redact_headers,
+        ),
+        request_body_size: har_request_body_size,
+        request_body_text: har_request_body_text,
+        redact_headers: settings.redact_headers,
+        tag: tag.clone(),
+    });
+
+    // Checked after the request is fully built (so a mock still sees the
+    // final method/URL, including query params) but before it's handed to
+    // a client, so a match returns through the exact same status-checking
+    // and body-decoding path below as a real response would.
+    if let Some(mock) = client.resolve_mock(request.method().as_str(), request.url().as_str())? {
+        if let Some(capture) = &har_capture {
+            let response_headers = har::redact_headers(&mock.headers, &capture.redact_headers);
+            let response_body_size = Some(mock.body.len() as u64);
+            client.record_har_entry(capture.to_entry(
+                mock.status_code,
+                response_headers,
+                response_body_size,
+            ));
+        }
+        let response = build_mock_response(mock, request.url().clone())?;
+        return Ok((
+            response,
+            client,
+            None,
+            request_body_hash,
+            ResponseSource::Mock,
+            body_codec,
+        ));
+    }
+
+    // The request above is always built via `client.client` (so client
+    // defaults like headers still apply), but it's just method+URL+headers+
+    // body and can be executed on any `reqwest::Client`, so a per-request
+    // compression, certificate, proxy, nodelay, or connection-lease override
+    // only has to change which client executes it. A compression override
+    // takes precedence over a connection lease, which takes precedence over
+    // a certificate override, which takes precedence over a proxy override,
+    // which takes precedence over a nodelay override, if more than one is
+    // given, since combining them would mean building yet another dedicated
+    // client just for that combination.
+    let executing_client = match (
+        request_compression,
+        &connection_lease,
+        &client_certificate,
+        &proxy_override,
+        tcp_nodelay,
+    ) {
+        (Some(compression), _, _, _, _) => client.client_for_compression(compression)?,
+        (None, Some(lease), _, _, _) => lease.client.clone(),
+        (None, None, Some(cert), _, _) => client.client_for_certificate(cert)?,
+        (None, None, None, Some(proxy), _) => client.client_for_proxy(proxy)?,
+        (None, None, None, None, Some(nodelay)) => client.client_for_nodelay(nodelay)?,
+        (None, None, None, None, None) => client.client.clone(),
+    };
+
+    // See `RequestClient::lease`: held across both the initial send and the
+    // 401-refresh retry below, so a burst of leased requests never overlaps
+    // on the wire even if the caller fires them concurrently.
+    let _lease_permit = match &connection_lease {
+        Some(lease) => Some(
+            lease
+                .permit
+                .clone()
+                .acquire_owned()
+                .await
+                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
+        ),
+        None => None,
+    };
+
+    let retry_request_for_401 = if client.settings.on_unauthorized.is_some() {
+        try_clone_or_replay(&request, replay_body.as_deref())
+    } else {
+        None
+    };
+
+    // Held across both the initial send and the 401-refresh retry below,
+    // so both count as the same one logical request against the host's
+    // cap. Dropping this (including via the outer cancellation
+    // `tokio::select!` in `make_http_request`/etc. dropping this whole
+    // future) releases the permit automatically.
+    let _host_permit = match request
+        .url()
+        .host_str()
+        .and_then(|host| client.semaphore_for_host(host))
+    {
+        Some(semaphore) => Some(
+            semaphore
+                .acquire_owned()
+                .await
+                .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?,
+        ),
+        None => None,
+    };
+
+    let response = execute_with_connect_retries(
+        &executing_client,
+        request,
+        &client.settings.redirect_settings,
+        client.settings.require_https,
+        client.settings.referer,
+        client.settings.connect_retries,
+        replay_body.as_deref(),
+    )
+    .await?;
+
+    let mut retried = false;
+    let response = if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+        match retry_after_refresh(&client, &executing_client, retry_request_for_401).await? {
+            Some(retried_response) => {
+                retried = true;
+                retried_response
             }
+            None => response,
         }
-    })?;
+    } else {
+        response
+    };
+
+    if client.settings.reject_ambiguous_content_length {
+        smuggling::check_for_smuggling_signature(response.headers())
+            .map_err(RhttpError::RhttpProtocolError)?;
+    }
+
+    if let Some(capture) = &har_capture {
+        let response_headers =
+            har::redact_headers(&header_to_vec(response.headers()), &capture.redact_headers);
+        client.record_har_entry(capture.to_entry(
+            response.status().as_u16(),
+            response_headers,
+            response.content_length(),
+        ));
+    }
 
-    if client.throw_on_status_code {
+    // `None` defers to the client's own setting; `Some(_)` overrides it
+    // for this one request only.
+    //
+    // A `412 Precondition Failed` from an `if_match` we sent ourselves is
+    // excluded even when `throw_on_status` is on: it's the expected way for
+    // an optimistic-concurrency check to fail, and the caller needs the
+    // response's current `etag` to retry, not a thrown error that discards
+    // it.
+    if throw_on_status.unwrap_or(client.throw_on_status_code)
+        && !(if_match.is_some() && response.status() == reqwest::StatusCode::PRECONDITION_FAILED)
+    {
         let status = response.status();
         if status.is_client_error() || status.is_server_error() {
             return Err(RhttpError::RhttpStatusCodeError(
@@ -496,11 +3183,457 @@ async fn make_http_request_helper(
                     ),
                     _ => HttpResponseBody::Stream,
                 },
+                tag,
             ));
         }
     }
 
-    Ok(response)
+    let debug_info = if client.settings.capture_debug_info {
+        Some(RequestDebugInfo {
+            negotiated_version: HttpVersion::from_version(response.version()),
+            proxy_used: proxy_override.map(|proxy| proxy.url),
+            timeout_applied: client
+                .settings
+                .timeout_settings
+                .as_ref()
+                .and_then(|t| t.timeout),
+            connection_reused: None,
+            retried,
+        })
+    } else {
+        None
+    };
+
+    Ok((
+        response,
+        client,
+        debug_info,
+        request_body_hash,
+        ResponseSource::Network,
+        body_codec,
+    ))
+}
+
+fn map_execute_error(e: reqwest::Error) -> RhttpError {
+    if e.is_redirect() {
+        // Same hacky debug-string sniff as the cert/headers-too-large cases
+        // below: `build_redirect_policy` raises `RhttpError::RhttpInsecureScheme`
+        // through `Attempt::error`, but reqwest only exposes it back to us
+        // as an opaque, type-erased source.
+        let is_insecure_scheme = match e.source() {
+            Some(inner) => format!("{:?}", inner).contains("RhttpInsecureScheme"),
+            None => false,
+        };
+        if is_insecure_scheme {
+            RhttpError::RhttpInsecureScheme(e.url().map(|u| u.to_string()).unwrap_or_default())
+        } else {
+            RhttpError::RhttpRedirectError
+        }
+    } else if e.is_timeout() {
+        // `is_connect` narrows down one phase reqwest does expose; DNS, TLS,
+        // and awaiting-headers timeouts all collapse into the same flag as
+        // the overall request timeout, so they fall back to `Total`. See
+        // `TimeoutPhase`.
+        if e.is_connect() {
+            RhttpError::RhttpTimeoutError(TimeoutPhase::Connect)
+        } else {
+            RhttpError::RhttpTimeoutError(TimeoutPhase::Total)
+        }
+    } else {
+        // We use the debug string because it contains more information
+        let inner = e.source();
+        let is_cert_error = match inner {
+            // TODO: This is a hacky way to check if the error is a certificate error
+            Some(inner) => format!("{:?}", inner).contains("InvalidCertificate"),
+            None => false,
+        };
+        // Same hacky approach for a header list that exceeded
+        // `max_response_header_bytes`: h2 doesn't give us a typed error
+        // for this through reqwest's public API.
+        let is_headers_too_large = match inner {
+            Some(inner) => format!("{:?}", inner).contains("EnhanceYourCalm"),
+            None => false,
+        };
+
+        if is_cert_error {
+            RhttpError::RhttpInvalidCertificateError(format!("{:?}", inner.unwrap()))
+        } else if is_headers_too_large {
+            RhttpError::RhttpHeadersTooLarge
+        } else if e.is_connect() {
+            // The attempted-addresses list is always empty: hyper-util's
+            // connector tries each resolved address in turn but only
+            // surfaces the last one's error, with no per-address address/
+            // reason pairs exposed through reqwest's public API -- there's
+            // no happy-eyeballs-attempt hook to aggregate them from.
+            RhttpError::RhttpConnectionError(format!("{:?}", inner.unwrap()), Vec::new())
+        } else {
+            RhttpError::RhttpUnknownError(match inner {
+                Some(inner) => format!("{inner:?}"),
+                None => format!("{e:?}"),
+            })
+        }
+    }
+}
+
+/// Base delay `execute_with_connect_retries` waits between attempts,
+/// scaled linearly by attempt number so a persistently unreachable host
+/// doesn't get hammered.
+const CONNECT_RETRY_BACKOFF: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// See `ClientSettings::body_replay_threshold_bytes`.
+const DEFAULT_BODY_REPLAY_THRESHOLD_BYTES: u64 = 64 * 1024;
+
+/// Like `Request::try_clone`, but falls back to rebuilding `request` with
+/// `replay_body` as a fresh body when the original can't be cloned -- e.g.
+/// it was wrapped in a stream for `chunked_body`/`throttled_body` despite
+/// being small. `replay_body` is only ever `Some` for a `HttpBody::Text`/
+/// `Bytes` body at or under `ClientSettings::body_replay_threshold_bytes`
+/// (see `make_http_request_helper`), so a genuinely streamed
+/// (`HttpBody::BytesStream`) or oversized body still isn't replayable.
+fn try_clone_or_replay(
+    request: &reqwest::Request,
+    replay_body: Option<&[u8]>,
+) -> Option<reqwest::Request> {
+    request.try_clone().or_else(|| {
+        let bytes = replay_body?;
+        let mut cloned = reqwest::Request::new(request.method().clone(), request.url().clone());
+        *cloned.timeout_mut() = request.timeout().copied();
+        *cloned.headers_mut() = request.headers().clone();
+        *cloned.version_mut() = request.version();
+        *cloned.body_mut() = Some(reqwest::Body::from(bytes.to_vec()));
+        Some(cloned)
+    })
+}
+
+/// Applies `ClientSettings::connect_retries` around whichever way `request`
+/// is actually sent (plain `execute`, or `execute_preserving_method` when
+/// redirects are being followed by hand). Only a connect-phase failure --
+/// mapped to `RhttpError::RhttpConnectionError` by `map_execute_error` --
+/// is retried; a failure that happened after the request was sent is
+/// returned as-is, since resending it could duplicate a non-idempotent
+/// request.
+async fn execute_with_connect_retries(
+    executing_client: &reqwest::Client,
+    mut request: reqwest::Request,
+    redirect_settings: &Option<RedirectSettings>,
+    require_https: bool,
+    referer: bool,
+    connect_retries: u32,
+    replay_body: Option<&[u8]>,
+) -> Result<Response, RhttpError> {
+    let mut attempt = 0;
+    loop {
+        let retry_request = if attempt < connect_retries {
+            try_clone_or_replay(&request, replay_body)
+        } else {
+            None
+        };
+
+        let result = match redirect_settings {
+            Some(RedirectSettings::LimitedRedirectsPreserveMethod(max_redirects)) => {
+                execute_preserving_method(
+                    executing_client,
+                    request,
+                    *max_redirects,
+                    require_https,
+                    referer,
+                    replay_body,
+                )
+                .await
+            }
+            _ => executing_client
+                .execute(request)
+                .await
+                .map_err(map_execute_error),
+        };
+
+        match result {
+            Ok(response) => return Ok(response),
+            Err(e) => {
+                let Some(next_request) = retry_request.filter(|_| {
+                    attempt < connect_retries && matches!(e, RhttpError::RhttpConnectionError(_, _))
+                }) else {
+                    return Err(e);
+                };
+                request = next_request;
+                attempt += 1;
+                tokio::time::sleep(CONNECT_RETRY_BACKOFF * attempt).await;
+            }
+        }
+    }
+}
+
+/// Re-issues `request` by hand on 301/302/303 redirects, keeping the
+/// original method and body instead of reqwest's default of rewriting them
+/// to a bodyless GET. Only called when `RedirectSettings::
+/// LimitedRedirectsPreserveMethod` set the underlying client's own redirect
+/// policy to `Policy::none()`, so this loop is the only thing following
+/// redirects at all.
+///
+/// A non-replayable body (e.g. `HttpBody::BytesStream`, or a buffered one
+/// over `ClientSettings::body_replay_threshold_bytes`) can't be resent to
+/// the redirect target, so a redirect response with one fails with
+/// `RhttpError::RhttpUnsupportedError` instead of silently dropping the
+/// body or following the redirect anyway.
+async fn execute_preserving_method(
+    executing_client: &reqwest::Client,
+    mut request: reqwest::Request,
+    max_redirects: i32,
+    require_https: bool,
+    referer: bool,
+    replay_body: Option<&[u8]>,
+) -> Result<Response, RhttpError> {
+    for _ in 0..=max_redirects {
+        let retry_request = try_clone_or_replay(&request, replay_body);
+        let previous_url = request.url().clone();
+
+        let response = executing_client
+            .execute(request)
+            .await
+            .map_err(map_execute_error)?;
+
+        if !matches!(
+            response.status(),
+            reqwest::StatusCode::MOVED_PERMANENTLY
+                | reqwest::StatusCode::FOUND
+                | reqwest::StatusCode::SEE_OTHER
+        ) {
+            return Ok(response);
+        }
+
+        let Some(location) = response
+            .headers()
+            .get(header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+        else {
+            return Ok(response);
+        };
+
+        let next_url = response
+            .url()
+            .join(location)
+            .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+
+        if require_https && next_url.scheme() != "https" {
+            return Err(RhttpError::RhttpInsecureScheme(next_url.to_string()));
+        }
+
+        let mut next_request = retry_request.ok_or_else(|| {
+            RhttpError::RhttpUnsupportedError(
+                "preserve_method_on_redirect requires a replayable request body, but this request's body can't be resent (e.g. a byte stream body)".to_string(),
+            )
+        })?;
+
+        if referer {
+            if let Some(value) = referer::header_for_redirect(&previous_url, &next_url) {
+                if let Ok(value) = HeaderValue::from_str(&value) {
+                    next_request.headers_mut().insert(header::REFERER, value);
+                }
+            } else {
+                next_request.headers_mut().remove(header::REFERER);
+            }
+        }
+
+        *next_request.url_mut() = next_url;
+
+        request = next_request;
+    }
+
+    Err(RhttpError::RhttpRedirectError)
+}
+
+/// Retries a request once after a 401, using `ClientSettings::on_unauthorized`
+/// to obtain a fresh `Authorization` value. `retry_request` is a clone of the
+/// original request taken before it was first sent; returns `Ok(None)` to
+/// leave the original 401 response as-is (no callback configured, the
+/// callback gave up, or the request body wasn't replayable).
+async fn retry_after_refresh(
+    client: &RequestClient,
+    executing_client: &reqwest::Client,
+    retry_request: Option<reqwest::Request>,
+) -> Result<Option<Response>, RhttpError> {
+    let Some(mut retry_request) = retry_request else {
+        return Ok(None);
+    };
+
+    let previous_authorization = retry_request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+
+    let Some(new_authorization) = client
+        .refresh_authorization(previous_authorization.as_deref())
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let header_value = HeaderValue::from_str(&new_authorization)
+        .map_err(|e| RhttpError::RhttpUnknownError(e.to_string()))?;
+    retry_request
+        .headers_mut()
+        .insert(header::AUTHORIZATION, header_value);
+
+    let response = executing_client
+        .execute(retry_request)
+        .await
+        .map_err(map_execute_error)?;
+    Ok(Some(response))
+}
+
+/// Wraps `bytes` as a single-chunk stream body so reqwest treats its length
+/// as unknown, forcing HTTP/1.1 chunked framing instead of `Content-Length`.
+fn chunked_body(bytes: Vec<u8>) -> reqwest::Body {
+    reqwest::Body::wrap_stream(futures_util::stream::once(async move {
+        Ok::<Vec<u8>, RhttpError>(bytes)
+    }))
+}
+
+/// The chunk size `throttled_body` splits an in-memory upload body into, so
+/// the token bucket can pace it smoothly instead of releasing it all at once.
+const THROTTLE_CHUNK_SIZE: usize = 16 * 1024;
+
+/// Wraps `stream` so each chunk is paced against `bucket`, weighted by
+/// `weight` -- see `TokenBucket::consume_weighted`. `bucket` is typically
+/// shared with every other request currently using the same client's
+/// `BandwidthSettings` cap, so `weight` is this request's
+/// `BandwidthPriority` share of it rather than a fixed rate of its own.
+fn throttle_stream<S, B, E>(
+    stream: S,
+    bucket: std::sync::Arc<tokio::sync::Mutex<TokenBucket>>,
+    weight: f64,
+) -> impl futures_util::Stream<Item = Result<B, E>>
+where
+    S: futures_util::Stream<Item = Result<B, E>>,
+    B: AsRef<[u8]>,
+{
+    stream.then(move |chunk| {
+        let bucket = bucket.clone();
+        async move {
+            if let Ok(bytes) = &chunk {
+                bucket
+                    .lock()
+                    .await
+                    .consume_weighted(bytes.as_ref().len(), weight)
+                    .await;
+            }
+            chunk
+        }
+    })
+}
+
+/// Wraps `stream` so it hashes each chunk with a running CRC-32 as it's sent,
+/// and appends the hex-encoded checksum as an `x-checksum` trailer frame once
+/// the stream is exhausted. See `TrailerChecksumAlgorithm::Crc32`.
+///
+/// Requires HTTP/2 (or chunked HTTP/1.1 framing with the right server
+/// support) to actually deliver the trailer; reqwest/hyper handle that
+/// negotiation transparently, this just needs to emit the trailer frame.
+fn checksum_trailer_body(
+    stream: impl futures_util::Stream<Item = Result<Vec<u8>, RhttpError>> + Send + Sync + 'static,
+) -> reqwest::Body {
+    let frames = futures_util::stream::unfold(
+        (Box::pin(stream), crc32fast::Hasher::new(), false),
+        |(mut stream, mut hasher, done)| async move {
+            if done {
+                return None;
+            }
+            match stream.next().await {
+                Some(Ok(chunk)) => {
+                    hasher.update(&chunk);
+                    let frame = http_body::Frame::data(bytes::Bytes::from(chunk));
+                    Some((Ok(frame), (stream, hasher, false)))
+                }
+                Some(Err(e)) => Some((Err(e), (stream, hasher, true))),
+                None => {
+                    let mut trailers = header::HeaderMap::new();
+                    let checksum = checksum_trailer::hex(hasher.clone().finalize());
+                    if let Ok(value) = HeaderValue::from_str(&checksum) {
+                        trailers.insert(checksum_trailer::TRAILER_HEADER_NAME, value);
+                    }
+                    Some((
+                        Ok(http_body::Frame::trailers(trailers)),
+                        (stream, hasher, true),
+                    ))
+                }
+            }
+        },
+    );
+    reqwest::Body::wrap(http_body_util::StreamBody::new(frames))
+}
+
+/// Joins already-JSON-encoded `elements` into one `[elem,elem,...]` buffer.
+/// See `HttpBody::JsonArrayStream`.
+fn json_array_bytes(elements: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut buffer = vec![b'['];
+    for (i, element) in elements.into_iter().enumerate() {
+        if i > 0 {
+            buffer.push(b',');
+        }
+        buffer.extend(element);
+    }
+    buffer.push(b']');
+    buffer
+}
+
+/// Wraps `stream` -- each item being one already-JSON-encoded array
+/// element -- into `[elem,elem,...]` framing, emitting the opening bracket
+/// alongside the first element (or alone, for an empty stream) and the
+/// closing bracket once the stream ends, so the whole array is never
+/// buffered at once. See `HttpBody::JsonArrayStream`.
+fn json_array_stream_body(
+    stream: impl futures_util::Stream<Item = Result<Vec<u8>, RhttpError>> + Send + Sync + 'static,
+) -> impl futures_util::Stream<Item = Result<Vec<u8>, RhttpError>> {
+    enum State<S> {
+        NotStarted(S),
+        InProgress(S),
+        Done,
+    }
+    futures_util::stream::unfold(State::NotStarted(Box::pin(stream)), |state| async move {
+        match state {
+            State::NotStarted(mut stream) => match stream.next().await {
+                Some(Ok(chunk)) => {
+                    let mut framed = Vec::with_capacity(chunk.len() + 1);
+                    framed.push(b'[');
+                    framed.extend(chunk);
+                    Some((Ok(framed), State::InProgress(stream)))
+                }
+                Some(Err(e)) => Some((Err(e), State::Done)),
+                None => Some((Ok(vec![b'[', b']']), State::Done)),
+            },
+            State::InProgress(mut stream) => match stream.next().await {
+                Some(Ok(chunk)) => {
+                    let mut framed = Vec::with_capacity(chunk.len() + 1);
+                    framed.push(b',');
+                    framed.extend(chunk);
+                    Some((Ok(framed), State::InProgress(stream)))
+                }
+                Some(Err(e)) => Some((Err(e), State::Done)),
+                None => Some((Ok(vec![b']']), State::Done)),
+            },
+            State::Done => None,
+        }
+    })
+}
+
+/// Splits `bytes` into fixed-size chunks and paces them against `bucket`
+/// weighted by `weight`, forcing chunked framing the same way `chunked_body`
+/// does. See `BandwidthSettings::upload_bps` and `BandwidthPriority`.
+fn throttled_body(
+    bytes: Vec<u8>,
+    bucket: std::sync::Arc<tokio::sync::Mutex<TokenBucket>>,
+    weight: f64,
+) -> reqwest::Body {
+    let chunks: Vec<Result<Vec<u8>, RhttpError>> = bytes
+        .chunks(THROTTLE_CHUNK_SIZE)
+        .map(|chunk| Ok(chunk.to_vec()))
+        .collect();
+    reqwest::Body::wrap_stream(throttle_stream(
+        futures_util::stream::iter(chunks),
+        bucket,
+        weight,
+    ))
 }
 
 fn header_to_vec(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
@@ -510,6 +3643,102 @@ fn header_to_vec(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)>
         .collect()
 }
 
+fn parse_alt_svc(headers: &reqwest::header::HeaderMap) -> Vec<AltSvcEntry> {
+    headers
+        .get_all(header::ALT_SVC)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(alt_svc::parse)
+        .collect()
+}
+
+fn parse_suggested_filename(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(header::CONTENT_DISPOSITION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(content_disposition::extract_filename)
+}
+
+fn parse_etag(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(header::ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(|s| s.to_string())
+}
+
+/// Appends `forwarded_for` to `headers`' existing `X-Forwarded-For` and
+/// `Forwarded` values (adding them if absent), for `relay_request` to record
+/// the original client's hop on the destination request. See
+/// `utils::forwarded`.
+fn apply_forwarded_headers(
+    headers: Option<HttpHeaders>,
+    forwarded_for: &ForwardedFor,
+) -> Result<HttpHeaders, RhttpError> {
+    let mut list: Vec<(String, String)> = match headers {
+        None => Vec::new(),
+        Some(HttpHeaders::Map(map)) => map.into_iter().collect(),
+        Some(HttpHeaders::List(list)) => list,
+        Some(HttpHeaders::Raw(_)) => {
+            return Err(RhttpError::RhttpUnsupportedError(
+                "raw header blocks are not supported: reqwest normalizes and validates headers through http::HeaderMap before sending".to_string(),
+            ));
+        }
+    };
+
+    let existing_xff = list
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("x-forwarded-for"))
+        .map(|(_, v)| v.clone());
+    let new_xff =
+        forwarded::append_x_forwarded_for(existing_xff.as_deref(), &forwarded_for.client_addr);
+    list.retain(|(k, _)| !k.eq_ignore_ascii_case("x-forwarded-for"));
+    list.push(("X-Forwarded-For".to_string(), new_xff));
+
+    let existing_forwarded = list
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("forwarded"))
+        .map(|(_, v)| v.clone());
+    let new_forwarded = forwarded::append_forwarded(
+        existing_forwarded.as_deref(),
+        forwarded::ForwardedHop {
+            for_addr: &forwarded_for.client_addr,
+            proto: &forwarded_for.proto,
+            host: forwarded_for.host.as_deref(),
+        },
+    );
+    list.retain(|(k, _)| !k.eq_ignore_ascii_case("forwarded"));
+    list.push(("Forwarded".to_string(), new_forwarded));
+
+    Ok(HttpHeaders::List(list))
+}
+
+/// Adds `Accept-Encoding: gzip` to `headers` if not already present, for
+/// `make_http_request_receive_ndjson_inner`'s `decompress_gzip_stream` to
+/// negotiate gzip itself after asking reqwest not to auto-decode it (see
+/// `RequestCompression`). Leaves an existing `Accept-Encoding` (of any
+/// value) untouched, on the assumption the caller set it deliberately.
+fn ensure_accept_encoding_gzip(headers: Option<HttpHeaders>) -> Result<HttpHeaders, RhttpError> {
+    let mut list: Vec<(String, String)> = match headers {
+        None => Vec::new(),
+        Some(HttpHeaders::Map(map)) => map.into_iter().collect(),
+        Some(HttpHeaders::List(list)) => list,
+        Some(HttpHeaders::Raw(_)) => {
+            return Err(RhttpError::RhttpUnsupportedError(
+                "raw header blocks are not supported: reqwest normalizes and validates headers through http::HeaderMap before sending".to_string(),
+            ));
+        }
+    };
+
+    if !list
+        .iter()
+        .any(|(k, _)| k.eq_ignore_ascii_case("accept-encoding"))
+    {
+        list.push(("Accept-Encoding".to_string(), "gzip".to_string()));
+    }
+
+    Ok(HttpHeaders::List(list))
+}
+
 pub fn cancel_request(token: &CancellationToken) {
     token.cancel();
 }
@@ -1,5 +1,8 @@
+pub mod accept;
 pub mod client;
 pub mod error;
+pub mod grpc_web;
 pub mod http;
 pub mod init;
+pub mod patch;
 pub mod stream;
@@ -0,0 +1,28 @@
+use crate::utils::patch;
+use flutter_rust_bridge::frb;
+
+/// The `Content-Type` for a JSON Merge Patch body (RFC 7396). Pass the
+/// result as a header via `HttpHeaders`, alongside a `HttpBody::Text`/
+/// `Bytes` body containing the merge document.
+#[frb(sync)]
+pub fn merge_patch_content_type() -> String {
+    patch::MERGE_PATCH_CONTENT_TYPE.to_string()
+}
+
+/// The `Content-Type` for a JSON Patch body (RFC 6902). Use together with
+/// `validate_json_patch_body` before sending, since a malformed JSON Patch
+/// document is a request the server would reject anyway.
+#[frb(sync)]
+pub fn json_patch_content_type() -> String {
+    patch::JSON_PATCH_CONTENT_TYPE.to_string()
+}
+
+/// Validates that `body` is well-formed JSON Patch (RFC 6902): a JSON array
+/// of operation objects, each with at least an `op` and a `path` member.
+/// Returns an error message describing the first problem found; call this
+/// before sending a PATCH request with `json_patch_content_type()` so a
+/// malformed body fails locally instead of round-tripping to the server.
+#[frb(sync)]
+pub fn validate_json_patch_body(body: String) -> Result<(), String> {
+    patch::validate_json_patch(&body)
+}
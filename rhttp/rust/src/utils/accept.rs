@@ -0,0 +1,105 @@
+/// One acceptable media type and its q-value (relative quality, `0.0..=1.0`)
+/// for a structured `Accept` header. See `build_header`.
+pub(crate) struct AcceptEntry {
+    pub media_type: String,
+    pub q: f32,
+}
+
+/// Formats a list of `(media_type, q)` pairs into a canonical `Accept`
+/// header value, e.g. `application/json, text/xml;q=0.5` -- the first entry
+/// (assumed to be the caller's most-preferred type) omits its q-value
+/// rather than writing the redundant `;q=1`, matching how most HTTP clients
+/// format a `q=1` entry. Later entries always include `;q=` with the value
+/// rounded to 3 decimal places, per RFC 7231's `qvalue` grammar.
+///
+/// Returns an error message (rather than panicking) if `media_types` is
+/// empty, a media type doesn't contain a `/`, or a q-value is outside
+/// `0.0..=1.0`.
+pub(crate) fn build_header(media_types: &[AcceptEntry]) -> Result<String, String> {
+    if media_types.is_empty() {
+        return Err("at least one media type is required".to_string());
+    }
+
+    let mut parts = Vec::with_capacity(media_types.len());
+    for (i, entry) in media_types.iter().enumerate() {
+        if !entry.media_type.contains('/') {
+            return Err(format!(
+                "invalid media type '{}': expected a '/' separating type and subtype",
+                entry.media_type
+            ));
+        }
+        if !(0.0..=1.0).contains(&entry.q) {
+            return Err(format!(
+                "invalid q-value {} for '{}': must be between 0.0 and 1.0",
+                entry.q, entry.media_type
+            ));
+        }
+
+        if i == 0 && entry.q >= 1.0 {
+            parts.push(entry.media_type.clone());
+        } else {
+            parts.push(format!("{};q={}", entry.media_type, format_qvalue(entry.q)));
+        }
+    }
+
+    Ok(parts.join(", "))
+}
+
+/// Rounds `q` to 3 decimal places and trims trailing zeros, e.g. `0.5000001`
+/// -> `"0.5"`, `1.0` -> `"1"`.
+fn format_qvalue(q: f32) -> String {
+    let rounded = (q * 1000.0).round() / 1000.0;
+    let mut formatted = format!("{rounded:.3}");
+    while formatted.ends_with('0') {
+        formatted.pop();
+    }
+    if formatted.ends_with('.') {
+        formatted.pop();
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(media_type: &str, q: f32) -> AcceptEntry {
+        AcceptEntry {
+            media_type: media_type.to_string(),
+            q,
+        }
+    }
+
+    #[test]
+    fn test_first_entry_at_full_quality_omits_q() {
+        let header = build_header(&[entry("application/json", 1.0), entry("text/xml", 0.5)]);
+        assert_eq!(header, Ok("application/json, text/xml;q=0.5".to_string()));
+    }
+
+    #[test]
+    fn test_first_entry_below_full_quality_includes_q() {
+        let header = build_header(&[entry("application/json", 0.9)]);
+        assert_eq!(header, Ok("application/json;q=0.9".to_string()));
+    }
+
+    #[test]
+    fn test_qvalue_rounds_to_three_decimals() {
+        let header = build_header(&[entry("application/json", 1.0), entry("text/xml", 0.33333)]);
+        assert_eq!(header, Ok("application/json, text/xml;q=0.333".to_string()));
+    }
+
+    #[test]
+    fn test_rejects_empty_list() {
+        assert!(build_header(&[]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_media_type_without_slash() {
+        assert!(build_header(&[entry("json", 1.0)]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_q() {
+        assert!(build_header(&[entry("application/json", 1.5)]).is_err());
+    }
+}
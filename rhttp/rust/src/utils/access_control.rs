@@ -0,0 +1,134 @@
+use std::net::IpAddr;
+
+/// Returns `true` if `addr` should be rejected under `allow`/`deny`/
+/// `block_private_ranges`. `allow` takes priority over both `deny` and
+/// `block_private_ranges`, so a private address can still be reached if it's
+/// explicitly allow-listed.
+pub(crate) fn is_blocked(
+    addr: IpAddr,
+    allow: &[String],
+    deny: &[String],
+    block_private_ranges: bool,
+) -> bool {
+    if allow.iter().any(|rule| matches_rule(addr, rule)) {
+        return false;
+    }
+    if deny.iter().any(|rule| matches_rule(addr, rule)) {
+        return true;
+    }
+    block_private_ranges && is_private_range(addr)
+}
+
+/// Matches `addr` against `rule`, which is either a bare address
+/// (`"10.0.0.5"`) or a CIDR range (`"10.0.0.0/8"`).
+fn matches_rule(addr: IpAddr, rule: &str) -> bool {
+    match rule.split_once('/') {
+        Some((base, prefix_len)) => match (base.parse::<IpAddr>(), prefix_len.parse::<u32>()) {
+            (Ok(base), Ok(prefix_len)) => in_cidr(addr, base, prefix_len),
+            _ => false,
+        },
+        None => rule
+            .parse::<IpAddr>()
+            .map(|rule_addr| rule_addr == addr)
+            .unwrap_or(false),
+    }
+}
+
+fn in_cidr(addr: IpAddr, base: IpAddr, prefix_len: u32) -> bool {
+    match (addr, base) {
+        (IpAddr::V4(addr), IpAddr::V4(base)) => {
+            let prefix_len = prefix_len.min(32);
+            let mask = mask_for(prefix_len, 32) as u32;
+            u32::from(addr) & mask == u32::from(base) & mask
+        }
+        (IpAddr::V6(addr), IpAddr::V6(base)) => {
+            let prefix_len = prefix_len.min(128);
+            let mask = mask_for(prefix_len, 128);
+            u128::from(addr) & mask == u128::from(base) & mask
+        }
+        _ => false,
+    }
+}
+
+/// Builds a `bits`-wide mask with the top `prefix_len` bits set.
+fn mask_for(prefix_len: u32, bits: u32) -> u128 {
+    if prefix_len == 0 {
+        0
+    } else {
+        u128::MAX << (bits - prefix_len) & (u128::MAX >> (128 - bits))
+    }
+}
+
+fn is_private_range(addr: IpAddr) -> bool {
+    match addr {
+        IpAddr::V4(addr) => {
+            addr.is_private() || addr.is_loopback() || addr.is_link_local() || addr.is_unspecified()
+        }
+        IpAddr::V6(addr) => {
+            addr.is_loopback()
+                || addr.is_unspecified()
+                || addr.is_unique_local()
+                || addr.is_unicast_link_local()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_blocked_denies_exact_match() {
+        let deny = vec!["1.2.3.4".to_string()];
+        assert!(is_blocked("1.2.3.4".parse().unwrap(), &[], &deny, false));
+        assert!(!is_blocked("1.2.3.5".parse().unwrap(), &[], &deny, false));
+    }
+
+    #[test]
+    fn test_is_blocked_denies_cidr_range() {
+        let deny = vec!["10.0.0.0/8".to_string()];
+        assert!(is_blocked("10.1.2.3".parse().unwrap(), &[], &deny, false));
+        assert!(!is_blocked("11.1.2.3".parse().unwrap(), &[], &deny, false));
+    }
+
+    #[test]
+    fn test_is_blocked_allow_overrides_deny_and_private_ranges() {
+        let allow = vec!["10.0.0.5".to_string()];
+        let deny = vec!["10.0.0.0/8".to_string()];
+        assert!(!is_blocked(
+            "10.0.0.5".parse().unwrap(),
+            &allow,
+            &deny,
+            true
+        ));
+    }
+
+    #[test]
+    fn test_is_blocked_private_ranges_covers_metadata_address() {
+        assert!(is_blocked(
+            "169.254.169.254".parse().unwrap(),
+            &[],
+            &[],
+            true
+        ));
+        assert!(is_blocked("127.0.0.1".parse().unwrap(), &[], &[], true));
+        assert!(is_blocked("192.168.1.1".parse().unwrap(), &[], &[], true));
+        assert!(!is_blocked("8.8.8.8".parse().unwrap(), &[], &[], true));
+    }
+
+    #[test]
+    fn test_is_blocked_private_ranges_off_by_default() {
+        assert!(!is_blocked("192.168.1.1".parse().unwrap(), &[], &[], false));
+    }
+
+    #[test]
+    fn test_is_blocked_ipv6_unique_local() {
+        assert!(is_blocked("fc00::1".parse().unwrap(), &[], &[], true));
+        assert!(!is_blocked(
+            "2001:4860:4860::8888".parse().unwrap(),
+            &[],
+            &[],
+            true
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::api::http::AltSvcEntry;
+
+/// Parses an `Alt-Svc` header value (RFC 7838) into its alternatives.
+/// Returns an empty list for `clear` or anything unparseable.
+pub(crate) fn parse(header_value: &str) -> Vec<AltSvcEntry> {
+    let trimmed = header_value.trim();
+    if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("clear") {
+        return Vec::new();
+    }
+
+    split_unquoted(trimmed, ',')
+        .filter_map(|entry| parse_entry(entry.trim()))
+        .collect()
+}
+
+fn parse_entry(entry: &str) -> Option<AltSvcEntry> {
+    let mut parts = split_unquoted(entry, ';');
+    let (protocol, authority) = parts.next()?.split_once('=')?;
+
+    let mut max_age = None;
+    for param in parts {
+        if let Some((key, value)) = param.trim().split_once('=') {
+            if key.trim().eq_ignore_ascii_case("ma") {
+                max_age = value.trim().trim_matches('"').parse::<u32>().ok();
+            }
+        }
+    }
+
+    Some(AltSvcEntry {
+        protocol: protocol.trim().trim_matches('"').to_string(),
+        authority: authority.trim().trim_matches('"').to_string(),
+        max_age,
+    })
+}
+
+/// Splits `input` on `delim`, ignoring occurrences inside double quotes.
+fn split_unquoted(input: &str, delim: char) -> impl Iterator<Item = &str> {
+    let mut result = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        } else if c == delim && !in_quotes {
+            result.push(&input[start..i]);
+            start = i + c.len_utf8();
+        }
+    }
+    result.push(&input[start..]);
+
+    result.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_clear() {
+        assert!(parse("clear").is_empty());
+    }
+
+    #[test]
+    fn test_parse_single() {
+        let entries = parse("h3=\":443\"; ma=2592000");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].protocol, "h3");
+        assert_eq!(entries[0].authority, ":443");
+        assert_eq!(entries[0].max_age, Some(2592000));
+    }
+
+    #[test]
+    fn test_parse_multiple() {
+        let entries = parse("h3=\":443\"; ma=2592000, h3-29=\":443\"; ma=2592000");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].protocol, "h3");
+        assert_eq!(entries[1].protocol, "h3-29");
+    }
+
+    #[test]
+    fn test_parse_without_max_age() {
+        let entries = parse("h2=\"alt.example.com:443\"");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].authority, "alt.example.com:443");
+        assert_eq!(entries[0].max_age, None);
+    }
+}
@@ -0,0 +1,32 @@
+/// The trailer header name `checksum_trailer_body` (in `api::http`) sends
+/// once a `TrailerChecksumAlgorithm::Crc32` upload stream finishes.
+pub(crate) const TRAILER_HEADER_NAME: &str = "x-checksum";
+
+/// Hex-encodes a CRC-32 checksum, zero-padded to 8 digits, matching the
+/// format `checksum_trailer_body` sends in the `x-checksum` trailer.
+pub(crate) fn hex(checksum: u32) -> String {
+    format!("{checksum:08x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_zero_pads_short_values() {
+        assert_eq!(hex(0x0f), "0000000f");
+    }
+
+    #[test]
+    fn test_hex_matches_known_crc32_vector() {
+        // CRC-32 (IEEE 802.3) of b"123456789" is the standard check value.
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(b"123456789");
+        assert_eq!(hex(hasher.finalize()), "cbf43926");
+    }
+
+    #[test]
+    fn test_hex_full_width_value() {
+        assert_eq!(hex(0xffffffff), "ffffffff");
+    }
+}
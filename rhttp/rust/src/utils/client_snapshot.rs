@@ -0,0 +1,623 @@
+use crate::api::client::{
+    AccessControl, BandwidthSettings, CacheSettings, ClientSettings, CookieSettings,
+    DecompressionRule, DnsSettings, Http3Settings, ProxyCondition, ProxySettings,
+    QuicCongestionController, RawCaptureSettings, RedirectSettings, StaticDnsSettings, TcpSettings,
+    TimeoutSettings, TlsSettings, TlsVersion,
+};
+use crate::api::http::HttpVersionPref;
+use serde::{Deserialize, Serialize};
+
+/// A serializable snapshot of a `ClientSettings`, for shipping identical
+/// client configs across app instances or attaching to a bug report.
+///
+/// Deliberately excludes everything that can't round-trip through JSON:
+/// every callback field (`on_informational`, `on_pool_event`,
+/// `on_unauthorized`, `on_sign`, `on_generate_span_id`,
+/// `on_connection_established`, `certificate_verify_callback`,
+/// `DnsSettings::DynamicDns`'s resolver) is a
+/// Rust closure tied to the current process, so reconstructing a
+/// `ClientSettings` from a snapshot always leaves those unset. TLS client
+/// certificates are secrets: `has_client_certificate` records only whether
+/// one was configured, never the certificate or private key bytes, so a
+/// snapshot never carries plaintext key material -- reattach the real
+/// certificate separately after rebuilding the client.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ClientSettingsSnapshot {
+    pub http_version_pref: HttpVersionPrefSnapshot,
+    pub http3_settings: Option<Http3SettingsSnapshot>,
+    pub timeout_settings: Option<TimeoutSettingsSnapshot>,
+    pub throw_on_status_code: bool,
+    pub proxy_settings: Option<ProxySettingsSnapshot>,
+    pub redirect_settings: Option<RedirectSettingsSnapshot>,
+    pub tls_settings: Option<TlsSettingsSnapshot>,
+    pub dns_settings: Option<DnsSettingsSnapshot>,
+    pub user_agent: Option<String>,
+    pub cache_settings: Option<CacheSettingsSnapshot>,
+    pub max_response_header_bytes: Option<u32>,
+    pub unix_socket_path: Option<String>,
+    pub max_decompression_ratio: Option<f64>,
+    pub decompression_content_type_rules: Vec<DecompressionRuleSnapshot>,
+    pub max_total_connections: Option<usize>,
+    pub max_concurrent_per_host: Option<usize>,
+    pub byte_quota: Option<u64>,
+    pub offline_detection_ms: Option<i64>,
+    pub bandwidth_settings: Option<BandwidthSettingsSnapshot>,
+    pub require_https: bool,
+    pub reject_ambiguous_content_length: bool,
+    pub raw_capture: Option<RawCaptureSettingsSnapshot>,
+    pub access_control: Option<AccessControlSnapshot>,
+    pub http2_max_concurrent_streams_per_conn: Option<u32>,
+    pub capture_debug_info: bool,
+    pub connect_retries: u32,
+    pub body_replay_threshold_bytes: Option<u64>,
+    pub external_socket_fd: Option<i32>,
+    pub android_network_handle: Option<i64>,
+    pub tcp_settings: Option<TcpSettingsSnapshot>,
+    pub cookie_settings: Option<CookieSettingsSnapshot>,
+    pub referer: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) enum HttpVersionPrefSnapshot {
+    Http10,
+    Http11,
+    Http2,
+    Http3,
+    All,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Http3SettingsSnapshot {
+    pub congestion_controller: QuicCongestionControllerSnapshot,
+    pub max_idle_timeout_ms: Option<i64>,
+    pub enable_datagrams: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) enum QuicCongestionControllerSnapshot {
+    NewReno,
+    Cubic,
+    Bbr,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct TimeoutSettingsSnapshot {
+    pub timeout_ms: Option<i64>,
+    pub connect_timeout_ms: Option<i64>,
+    pub keep_alive_timeout_ms: Option<i64>,
+    pub keep_alive_ping_ms: Option<i64>,
+    pub connect_timeout_ipv6_ms: Option<i64>,
+    pub connect_timeout_ipv4_ms: Option<i64>,
+    pub tls_handshake_timeout_ms: Option<i64>,
+    pub continue_timeout_ms: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) enum ProxySettingsSnapshot {
+    NoProxy,
+    CustomProxyList(Vec<CustomProxySnapshot>),
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CustomProxySnapshot {
+    pub url: String,
+    pub condition: ProxyConditionSnapshot,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) enum ProxyConditionSnapshot {
+    Http,
+    Https,
+    All,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) enum RedirectSettingsSnapshot {
+    NoRedirect,
+    LimitedRedirects(i32),
+    LimitedRedirectsPreserveMethod(i32),
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct TlsSettingsSnapshot {
+    pub trust_root_certificates: bool,
+    pub trusted_root_certificates: Vec<Vec<u8>>,
+    pub verify_certificates: bool,
+    pub has_client_certificate: bool,
+    pub min_tls_version: Option<TlsVersionSnapshot>,
+    pub max_tls_version: Option<TlsVersionSnapshot>,
+    pub sni: bool,
+    pub enable_early_data: bool,
+    pub alpn_downgrade_hosts: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) enum TlsVersionSnapshot {
+    Tls1_2,
+    Tls1_3,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) enum DnsSettingsSnapshot {
+    StaticDns(StaticDnsSettingsSnapshot),
+
+    /// `DynamicDns`'s resolver is a closure and can't be serialized; its
+    /// presence is recorded in the snapshot JSON only. `from_snapshot` turns
+    /// it back into `dns_settings: None`, so a rebuilt client uses normal DNS
+    /// until a resolver is reattached.
+    DynamicDnsExcluded,
+
+    /// Like `DynamicDnsExcluded`, but for `SrvDns`'s resolver.
+    SrvDnsExcluded,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct StaticDnsSettingsSnapshot {
+    pub fallback: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CacheSettingsSnapshot {
+    pub max_entries: usize,
+    pub disk_cache_dir: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct DecompressionRuleSnapshot {
+    pub content_type: String,
+    pub decompress: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct BandwidthSettingsSnapshot {
+    pub download_bps: Option<u64>,
+    pub upload_bps: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct RawCaptureSettingsSnapshot {
+    pub max_bytes: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct AccessControlSnapshot {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+    pub block_private_ranges: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct TcpSettingsSnapshot {
+    pub fast_open: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CookieSettingsSnapshot {
+    pub store_cookies: bool,
+    pub max_cookies_per_domain: Option<usize>,
+    pub max_total_cookies: Option<usize>,
+    pub max_cookie_size_bytes: Option<usize>,
+}
+
+/// Builds a snapshot of `settings`, redacting secrets. See
+/// `ClientSettingsSnapshot`.
+pub(crate) fn to_snapshot(settings: &ClientSettings) -> ClientSettingsSnapshot {
+    ClientSettingsSnapshot {
+        http_version_pref: match settings.http_version_pref {
+            HttpVersionPref::Http10 => HttpVersionPrefSnapshot::Http10,
+            HttpVersionPref::Http11 => HttpVersionPrefSnapshot::Http11,
+            HttpVersionPref::Http2 => HttpVersionPrefSnapshot::Http2,
+            HttpVersionPref::Http3 => HttpVersionPrefSnapshot::Http3,
+            HttpVersionPref::All => HttpVersionPrefSnapshot::All,
+        },
+        http3_settings: settings
+            .http3_settings
+            .as_ref()
+            .map(|h| Http3SettingsSnapshot {
+                congestion_controller: match h.congestion_controller {
+                    QuicCongestionController::NewReno => QuicCongestionControllerSnapshot::NewReno,
+                    QuicCongestionController::Cubic => QuicCongestionControllerSnapshot::Cubic,
+                    QuicCongestionController::Bbr => QuicCongestionControllerSnapshot::Bbr,
+                },
+                max_idle_timeout_ms: h.max_idle_timeout.map(|d| d.num_milliseconds()),
+                enable_datagrams: h.enable_datagrams,
+            }),
+        timeout_settings: settings
+            .timeout_settings
+            .as_ref()
+            .map(|t| TimeoutSettingsSnapshot {
+                timeout_ms: t.timeout.map(|d| d.num_milliseconds()),
+                connect_timeout_ms: t.connect_timeout.map(|d| d.num_milliseconds()),
+                keep_alive_timeout_ms: t.keep_alive_timeout.map(|d| d.num_milliseconds()),
+                keep_alive_ping_ms: t.keep_alive_ping.map(|d| d.num_milliseconds()),
+                connect_timeout_ipv6_ms: t.connect_timeout_ipv6.map(|d| d.num_milliseconds()),
+                connect_timeout_ipv4_ms: t.connect_timeout_ipv4.map(|d| d.num_milliseconds()),
+                tls_handshake_timeout_ms: t.tls_handshake_timeout.map(|d| d.num_milliseconds()),
+                continue_timeout_ms: t.continue_timeout.map(|d| d.num_milliseconds()),
+            }),
+        throw_on_status_code: settings.throw_on_status_code,
+        proxy_settings: settings.proxy_settings.as_ref().map(|p| match p {
+            ProxySettings::NoProxy => ProxySettingsSnapshot::NoProxy,
+            ProxySettings::CustomProxyList(proxies) => ProxySettingsSnapshot::CustomProxyList(
+                proxies
+                    .iter()
+                    .map(|proxy| CustomProxySnapshot {
+                        url: proxy.url.clone(),
+                        condition: match proxy.condition {
+                            ProxyCondition::Http => ProxyConditionSnapshot::Http,
+                            ProxyCondition::Https => ProxyConditionSnapshot::Https,
+                            ProxyCondition::All => ProxyConditionSnapshot::All,
+                        },
+                    })
+                    .collect(),
+            ),
+        }),
+        redirect_settings: settings.redirect_settings.map(|r| match r {
+            RedirectSettings::NoRedirect => RedirectSettingsSnapshot::NoRedirect,
+            RedirectSettings::LimitedRedirects(n) => RedirectSettingsSnapshot::LimitedRedirects(n),
+            RedirectSettings::LimitedRedirectsPreserveMethod(n) => {
+                RedirectSettingsSnapshot::LimitedRedirectsPreserveMethod(n)
+            }
+        }),
+        tls_settings: settings.tls_settings.as_ref().map(|t| TlsSettingsSnapshot {
+            trust_root_certificates: t.trust_root_certificates,
+            trusted_root_certificates: t.trusted_root_certificates.clone(),
+            verify_certificates: t.verify_certificates,
+            has_client_certificate: t.client_certificate.is_some(),
+            min_tls_version: t.min_tls_version.map(tls_version_to_snapshot),
+            max_tls_version: t.max_tls_version.map(tls_version_to_snapshot),
+            sni: t.sni,
+            enable_early_data: t.enable_early_data,
+            alpn_downgrade_hosts: t.alpn_downgrade_hosts.clone(),
+        }),
+        dns_settings: settings.dns_settings.as_ref().map(|d| match d {
+            DnsSettings::StaticDns(s) => DnsSettingsSnapshot::StaticDns(static_dns_to_snapshot(s)),
+            DnsSettings::DynamicDns(_) => DnsSettingsSnapshot::DynamicDnsExcluded,
+            DnsSettings::SrvDns(_) => DnsSettingsSnapshot::SrvDnsExcluded,
+        }),
+        user_agent: settings.user_agent.clone(),
+        cache_settings: settings
+            .cache_settings
+            .as_ref()
+            .map(|c| CacheSettingsSnapshot {
+                max_entries: c.max_entries,
+                disk_cache_dir: c.disk_cache_dir.clone(),
+            }),
+        max_response_header_bytes: settings.max_response_header_bytes,
+        unix_socket_path: settings.unix_socket_path.clone(),
+        max_decompression_ratio: settings.max_decompression_ratio,
+        decompression_content_type_rules: settings
+            .decompression_content_type_rules
+            .iter()
+            .map(|rule| DecompressionRuleSnapshot {
+                content_type: rule.content_type.clone(),
+                decompress: rule.decompress,
+            })
+            .collect(),
+        max_total_connections: settings.max_total_connections,
+        max_concurrent_per_host: settings.max_concurrent_per_host,
+        byte_quota: settings.byte_quota,
+        offline_detection_ms: settings.offline_detection.map(|d| d.num_milliseconds()),
+        bandwidth_settings: settings
+            .bandwidth_settings
+            .map(|b| BandwidthSettingsSnapshot {
+                download_bps: b.download_bps,
+                upload_bps: b.upload_bps,
+            }),
+        require_https: settings.require_https,
+        reject_ambiguous_content_length: settings.reject_ambiguous_content_length,
+        raw_capture: settings.raw_capture.map(|r| RawCaptureSettingsSnapshot {
+            max_bytes: r.max_bytes,
+        }),
+        access_control: settings
+            .access_control
+            .as_ref()
+            .map(|a| AccessControlSnapshot {
+                allow: a.allow.clone(),
+                deny: a.deny.clone(),
+                block_private_ranges: a.block_private_ranges,
+            }),
+        http2_max_concurrent_streams_per_conn: settings.http2_max_concurrent_streams_per_conn,
+        capture_debug_info: settings.capture_debug_info,
+        connect_retries: settings.connect_retries,
+        body_replay_threshold_bytes: settings.body_replay_threshold_bytes,
+        external_socket_fd: settings.external_socket_fd,
+        android_network_handle: settings.android_network_handle,
+        tcp_settings: settings.tcp_settings.as_ref().map(|t| TcpSettingsSnapshot {
+            fast_open: t.fast_open,
+        }),
+        cookie_settings: settings
+            .cookie_settings
+            .as_ref()
+            .map(|c| CookieSettingsSnapshot {
+                store_cookies: c.store_cookies,
+                max_cookies_per_domain: c.max_cookies_per_domain,
+                max_total_cookies: c.max_total_cookies,
+                max_cookie_size_bytes: c.max_cookie_size_bytes,
+            }),
+        referer: settings.referer,
+    }
+}
+
+/// Rebuilds a `ClientSettings` from `snapshot`. Every excluded field (see
+/// `ClientSettingsSnapshot`) comes back as `None`/empty, not an error --
+/// the caller reattaches secrets and callbacks after this returns.
+pub(crate) fn from_snapshot(snapshot: ClientSettingsSnapshot) -> ClientSettings {
+    ClientSettings {
+        cookie_settings: snapshot.cookie_settings.map(|c| CookieSettings {
+            store_cookies: c.store_cookies,
+            max_cookies_per_domain: c.max_cookies_per_domain,
+            max_total_cookies: c.max_total_cookies,
+            max_cookie_size_bytes: c.max_cookie_size_bytes,
+        }),
+        http_version_pref: match snapshot.http_version_pref {
+            HttpVersionPrefSnapshot::Http10 => HttpVersionPref::Http10,
+            HttpVersionPrefSnapshot::Http11 => HttpVersionPref::Http11,
+            HttpVersionPrefSnapshot::Http2 => HttpVersionPref::Http2,
+            HttpVersionPrefSnapshot::Http3 => HttpVersionPref::Http3,
+            HttpVersionPrefSnapshot::All => HttpVersionPref::All,
+        },
+        http3_settings: snapshot.http3_settings.map(|h| Http3Settings {
+            congestion_controller: match h.congestion_controller {
+                QuicCongestionControllerSnapshot::NewReno => QuicCongestionController::NewReno,
+                QuicCongestionControllerSnapshot::Cubic => QuicCongestionController::Cubic,
+                QuicCongestionControllerSnapshot::Bbr => QuicCongestionController::Bbr,
+            },
+            max_idle_timeout: h.max_idle_timeout_ms.map(chrono::Duration::milliseconds),
+            enable_datagrams: h.enable_datagrams,
+        }),
+        timeout_settings: snapshot.timeout_settings.map(|t| TimeoutSettings {
+            timeout: t.timeout_ms.map(chrono::Duration::milliseconds),
+            connect_timeout: t.connect_timeout_ms.map(chrono::Duration::milliseconds),
+            keep_alive_timeout: t.keep_alive_timeout_ms.map(chrono::Duration::milliseconds),
+            keep_alive_ping: t.keep_alive_ping_ms.map(chrono::Duration::milliseconds),
+            connect_timeout_ipv6: t
+                .connect_timeout_ipv6_ms
+                .map(chrono::Duration::milliseconds),
+            connect_timeout_ipv4: t
+                .connect_timeout_ipv4_ms
+                .map(chrono::Duration::milliseconds),
+            tls_handshake_timeout: t
+                .tls_handshake_timeout_ms
+                .map(chrono::Duration::milliseconds),
+            continue_timeout: t.continue_timeout_ms.map(chrono::Duration::milliseconds),
+        }),
+        throw_on_status_code: snapshot.throw_on_status_code,
+        proxy_settings: snapshot.proxy_settings.map(|p| match p {
+            ProxySettingsSnapshot::NoProxy => ProxySettings::NoProxy,
+            ProxySettingsSnapshot::CustomProxyList(proxies) => ProxySettings::CustomProxyList(
+                proxies
+                    .into_iter()
+                    .map(|proxy| crate::api::client::CustomProxy {
+                        url: proxy.url,
+                        condition: match proxy.condition {
+                            ProxyConditionSnapshot::Http => ProxyCondition::Http,
+                            ProxyConditionSnapshot::Https => ProxyCondition::Https,
+                            ProxyConditionSnapshot::All => ProxyCondition::All,
+                        },
+                        pool_settings: None,
+                    })
+                    .collect(),
+            ),
+        }),
+        redirect_settings: snapshot.redirect_settings.map(|r| match r {
+            RedirectSettingsSnapshot::NoRedirect => RedirectSettings::NoRedirect,
+            RedirectSettingsSnapshot::LimitedRedirects(n) => RedirectSettings::LimitedRedirects(n),
+            RedirectSettingsSnapshot::LimitedRedirectsPreserveMethod(n) => {
+                RedirectSettings::LimitedRedirectsPreserveMethod(n)
+            }
+        }),
+        tls_settings: snapshot.tls_settings.map(|t| TlsSettings {
+            trust_root_certificates: t.trust_root_certificates,
+            trusted_root_certificates: t.trusted_root_certificates,
+            verify_certificates: t.verify_certificates,
+            client_certificate: None,
+            min_tls_version: t.min_tls_version.map(tls_version_from_snapshot),
+            max_tls_version: t.max_tls_version.map(tls_version_from_snapshot),
+            sni: t.sni,
+            enable_early_data: t.enable_early_data,
+            certificate_verify_callback: None,
+            fingerprint_profile: None,
+            alpn_downgrade_hosts: t.alpn_downgrade_hosts,
+            // Not carried over, same rationale as `StaticDnsSettings::overrides`
+            // in `static_dns_from_snapshot`: reproducing the exact override
+            // table isn't the point of a config snapshot, so it's left for the
+            // caller to reapply if needed.
+            sni_overrides: Default::default(),
+        }),
+        dns_settings: snapshot.dns_settings.and_then(|d| match d {
+            DnsSettingsSnapshot::StaticDns(s) => {
+                Some(DnsSettings::StaticDns(static_dns_from_snapshot(s)))
+            }
+            DnsSettingsSnapshot::DynamicDnsExcluded => None,
+            DnsSettingsSnapshot::SrvDnsExcluded => None,
+        }),
+        user_agent: snapshot.user_agent,
+        cache_settings: snapshot.cache_settings.map(|c| CacheSettings {
+            max_entries: c.max_entries,
+            disk_cache_dir: c.disk_cache_dir,
+        }),
+        max_response_header_bytes: snapshot.max_response_header_bytes,
+        unix_socket_path: snapshot.unix_socket_path,
+        on_informational: None,
+        max_decompression_ratio: snapshot.max_decompression_ratio,
+        decompression_content_type_rules: snapshot
+            .decompression_content_type_rules
+            .into_iter()
+            .map(|rule| DecompressionRule {
+                content_type: rule.content_type,
+                decompress: rule.decompress,
+            })
+            .collect(),
+        on_pool_event: None,
+        max_total_connections: snapshot.max_total_connections,
+        max_concurrent_per_host: snapshot.max_concurrent_per_host,
+        byte_quota: snapshot.byte_quota,
+        offline_detection: snapshot
+            .offline_detection_ms
+            .map(chrono::Duration::milliseconds),
+        on_unauthorized: None,
+        bandwidth_settings: snapshot.bandwidth_settings.map(|b| BandwidthSettings {
+            download_bps: b.download_bps,
+            upload_bps: b.upload_bps,
+        }),
+        on_sign: None,
+        on_generate_span_id: None,
+        require_https: snapshot.require_https,
+        reject_ambiguous_content_length: snapshot.reject_ambiguous_content_length,
+        raw_capture: snapshot.raw_capture.map(|r| RawCaptureSettings {
+            max_bytes: r.max_bytes,
+        }),
+        access_control: snapshot.access_control.map(|a| AccessControl {
+            allow: a.allow,
+            deny: a.deny,
+            block_private_ranges: a.block_private_ranges,
+        }),
+        http2_max_concurrent_streams_per_conn: snapshot.http2_max_concurrent_streams_per_conn,
+        capture_debug_info: snapshot.capture_debug_info,
+        connect_retries: snapshot.connect_retries,
+        body_replay_threshold_bytes: snapshot.body_replay_threshold_bytes,
+        external_socket_fd: snapshot.external_socket_fd,
+        android_network_handle: snapshot.android_network_handle,
+        tcp_settings: snapshot.tcp_settings.map(|t| TcpSettings {
+            fast_open: t.fast_open,
+        }),
+        on_connection_established: None,
+        referer: snapshot.referer,
+    }
+}
+
+fn tls_version_to_snapshot(v: TlsVersion) -> TlsVersionSnapshot {
+    match v {
+        TlsVersion::Tls1_2 => TlsVersionSnapshot::Tls1_2,
+        TlsVersion::Tls1_3 => TlsVersionSnapshot::Tls1_3,
+    }
+}
+
+fn tls_version_from_snapshot(v: TlsVersionSnapshot) -> TlsVersion {
+    match v {
+        TlsVersionSnapshot::Tls1_2 => TlsVersion::Tls1_2,
+        TlsVersionSnapshot::Tls1_3 => TlsVersion::Tls1_3,
+    }
+}
+
+fn static_dns_to_snapshot(s: &StaticDnsSettings) -> StaticDnsSettingsSnapshot {
+    // `overrides` isn't carried over: `DnsOverrideAddress` is plain data,
+    // but reproducing the exact override map isn't the point of a config
+    // snapshot the way the fallback host is, so it's left for the caller
+    // to reapply if needed.
+    StaticDnsSettingsSnapshot {
+        fallback: s.fallback.clone(),
+    }
+}
+
+fn static_dns_from_snapshot(s: StaticDnsSettingsSnapshot) -> StaticDnsSettings {
+    StaticDnsSettings {
+        overrides: Default::default(),
+        fallback: s.fallback,
+    }
+}
+
+/// Serializes `settings` to JSON, redacting secrets. See
+/// `ClientSettingsSnapshot`.
+pub(crate) fn serialize(settings: &ClientSettings) -> Result<String, String> {
+    serde_json::to_string(&to_snapshot(settings)).map_err(|e| e.to_string())
+}
+
+/// Rebuilds a `ClientSettings` from JSON produced by `serialize`.
+pub(crate) fn deserialize(json: &str) -> Result<ClientSettings, String> {
+    let snapshot: ClientSettingsSnapshot = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    Ok(from_snapshot(snapshot))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minimal_settings() -> ClientSettings {
+        ClientSettings {
+            cookie_settings: None,
+            http_version_pref: HttpVersionPref::All,
+            http3_settings: None,
+            timeout_settings: None,
+            throw_on_status_code: true,
+            proxy_settings: None,
+            redirect_settings: None,
+            tls_settings: None,
+            dns_settings: None,
+            user_agent: Some("rhttp-test".to_string()),
+            cache_settings: None,
+            max_response_header_bytes: None,
+            unix_socket_path: None,
+            on_informational: None,
+            max_decompression_ratio: None,
+            decompression_content_type_rules: Vec::new(),
+            on_pool_event: None,
+            max_total_connections: None,
+            max_concurrent_per_host: None,
+            byte_quota: None,
+            offline_detection: None,
+            on_unauthorized: None,
+            bandwidth_settings: None,
+            on_sign: None,
+            on_generate_span_id: None,
+            require_https: false,
+            reject_ambiguous_content_length: false,
+            raw_capture: None,
+            access_control: None,
+            http2_max_concurrent_streams_per_conn: None,
+            capture_debug_info: false,
+            connect_retries: 0,
+            body_replay_threshold_bytes: None,
+            external_socket_fd: None,
+            android_network_handle: None,
+            tcp_settings: None,
+            on_connection_established: None,
+            referer: true,
+        }
+    }
+
+    #[test]
+    fn test_round_trips_non_secret_config() {
+        let settings = minimal_settings();
+        let json = serialize(&settings).unwrap();
+        let rebuilt = deserialize(&json).unwrap();
+        assert_eq!(rebuilt.user_agent, Some("rhttp-test".to_string()));
+        assert!(rebuilt.throw_on_status_code);
+    }
+
+    #[test]
+    fn test_client_certificate_never_appears_in_json() {
+        let mut settings = minimal_settings();
+        settings.tls_settings = Some(TlsSettings {
+            trust_root_certificates: true,
+            trusted_root_certificates: Vec::new(),
+            verify_certificates: true,
+            client_certificate: Some(crate::api::client::ClientCertificate {
+                certificate: b"cert-secret".to_vec(),
+                private_key: b"key-secret".to_vec(),
+            }),
+            min_tls_version: None,
+            max_tls_version: None,
+            sni: true,
+            enable_early_data: false,
+            certificate_verify_callback: None,
+            fingerprint_profile: None,
+            alpn_downgrade_hosts: Vec::new(),
+            sni_overrides: Default::default(),
+        });
+
+        let json = serialize(&settings).unwrap();
+        assert!(!json.contains("cert-secret"));
+        assert!(!json.contains("key-secret"));
+        assert!(json.contains("\"has_client_certificate\":true"));
+
+        let rebuilt = deserialize(&json).unwrap();
+        assert!(rebuilt.tls_settings.unwrap().client_certificate.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_rejects_invalid_json() {
+        assert!(deserialize("not json").is_err());
+    }
+}
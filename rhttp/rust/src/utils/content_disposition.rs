@@ -0,0 +1,113 @@
+use percent_encoding::percent_decode_str;
+
+/// Extracts the suggested filename from a `Content-Disposition` header
+/// value (RFC 6266), preferring the RFC 5987/8187 extended `filename*`
+/// parameter (which carries an explicit charset and percent-encoding) over
+/// the plain `filename` parameter. Returns `None` if neither parameter is
+/// present or the extended form uses a charset other than UTF-8, since
+/// decoding to anything else isn't supported.
+///
+/// The result is sanitized with `sanitize` before being returned, so a
+/// malicious or careless server can't smuggle a path-traversal filename
+/// (e.g. `../../etc/passwd`) into a caller that joins it onto a directory
+/// unchecked.
+pub(crate) fn extract_filename(header_value: &str) -> Option<String> {
+    let mut plain = None;
+    let mut extended = None;
+
+    for param in header_value.split(';').skip(1) {
+        let Some((key, value)) = param.trim().split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        if key.eq_ignore_ascii_case("filename*") {
+            extended = parse_extended_value(value.trim());
+        } else if key.eq_ignore_ascii_case("filename") {
+            plain = Some(value.trim().trim_matches('"').to_string());
+        }
+    }
+
+    extended.or(plain).map(|name| sanitize(&name))
+}
+
+/// Parses an RFC 5987 `ext-value` (`charset'language'percent-encoded`).
+/// Only the UTF-8 charset is supported, matching every realistic server in
+/// practice; any other charset returns `None` rather than mojibake.
+fn parse_extended_value(value: &str) -> Option<String> {
+    let mut parts = value.splitn(3, '\'');
+    let charset = parts.next()?;
+    let _language = parts.next()?;
+    let encoded = parts.next()?;
+
+    if !charset.eq_ignore_ascii_case("utf-8") {
+        return None;
+    }
+
+    percent_decode_str(encoded)
+        .decode_utf8()
+        .ok()
+        .map(|s| s.into_owned())
+}
+
+/// Replaces path separators (`/` and `\`) with `:` so `name` joins onto a
+/// destination directory as a single path component, e.g. turning
+/// `../../etc/passwd` into `..:..:etc:passwd` rather than escaping the
+/// intended directory. `..` sequences themselves are left in place.
+fn sanitize(name: &str) -> String {
+    name.replace(['/', '\\'], ":")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extracts_plain_filename() {
+        assert_eq!(
+            extract_filename(r#"attachment; filename="report.pdf""#),
+            Some("report.pdf".to_string())
+        );
+    }
+
+    #[test]
+    fn test_prefers_extended_filename_over_plain() {
+        let header = "attachment; filename=\"fallback.txt\"; filename*=UTF-8''caf%C3%A9.txt";
+        assert_eq!(extract_filename(header), Some("café.txt".to_string()));
+    }
+
+    #[test]
+    fn test_returns_none_for_unsupported_charset() {
+        let header = "attachment; filename*=ISO-8859-1'en'na%EFve.txt";
+        assert_eq!(extract_filename(header), None);
+    }
+
+    #[test]
+    fn test_returns_none_without_a_filename_parameter() {
+        assert_eq!(extract_filename("inline"), None);
+    }
+
+    #[test]
+    fn test_sanitizes_path_traversal_in_plain_filename() {
+        assert_eq!(
+            extract_filename(r#"attachment; filename="../../etc/passwd""#),
+            Some("..:..:etc:passwd".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sanitizes_path_traversal_in_extended_filename() {
+        let header = "attachment; filename*=UTF-8''..%2F..%2Fetc%2Fpasswd";
+        assert_eq!(
+            extract_filename(header),
+            Some("..:..:etc:passwd".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sanitizes_backslashes() {
+        assert_eq!(
+            extract_filename(r#"attachment; filename="..\\..\\windows\\win.ini""#),
+            Some("..:..:windows:win.ini".to_string())
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use crate::api::http::ContentHashAlgorithm;
+use md5::{Digest, Md5};
+
+/// Computes a request body's digest, hex-encoded lowercase, for
+/// `HttpResponse::request_body_hash`.
+///
+/// `Sha256` isn't wired yet -- see `ContentHashAlgorithm::Sha256` -- so it
+/// always returns `None`.
+pub(crate) fn hex_digest(algorithm: ContentHashAlgorithm, body: &[u8]) -> Option<String> {
+    match algorithm {
+        ContentHashAlgorithm::Sha256 => None,
+        ContentHashAlgorithm::Md5 => {
+            let mut hasher = Md5::new();
+            hasher.update(body);
+            Some(hex_encode(&hasher.finalize()))
+        }
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_md5_matches_known_vector() {
+        assert_eq!(
+            hex_digest(ContentHashAlgorithm::Md5, b"abc"),
+            Some("900150983cd24fb0d6963f7d28e17f72".to_string())
+        );
+    }
+
+    #[test]
+    fn test_md5_empty_body() {
+        assert_eq!(
+            hex_digest(ContentHashAlgorithm::Md5, b""),
+            Some("d41d8cd98f00b204e9800998ecf8427e".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sha256_not_yet_wired_returns_none() {
+        assert_eq!(hex_digest(ContentHashAlgorithm::Sha256, b"abc"), None);
+    }
+}
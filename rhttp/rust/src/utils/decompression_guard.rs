@@ -0,0 +1,36 @@
+/// Whether `decompressed` bytes produced from `compressed` bytes so far
+/// exceeds `max_ratio`, for aborting an incremental decompression (e.g.
+/// `make_http_request_receive_ndjson_inner`'s `decompress_gzip_stream`)
+/// before a decompression bomb finishes unpacking. `compressed` is floored
+/// at 1 so a ratio check made before any input has been read yet doesn't
+/// divide by zero and treat the first byte of output as an infinite ratio.
+pub(crate) fn exceeds_ratio(compressed: u64, decompressed: u64, max_ratio: f64) -> bool {
+    let compressed = compressed.max(1) as f64;
+    decompressed as f64 > compressed * max_ratio
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceeds_ratio_within_bound() {
+        assert!(!exceeds_ratio(1000, 5000, 10.0));
+    }
+
+    #[test]
+    fn test_exceeds_ratio_over_bound() {
+        assert!(exceeds_ratio(1000, 10001, 10.0));
+    }
+
+    #[test]
+    fn test_exceeds_ratio_exactly_at_bound_is_not_exceeded() {
+        assert!(!exceeds_ratio(1000, 10000, 10.0));
+    }
+
+    #[test]
+    fn test_exceeds_ratio_floors_compressed_at_one() {
+        assert!(exceeds_ratio(0, 11, 10.0));
+        assert!(!exceeds_ratio(0, 10, 10.0));
+    }
+}
@@ -0,0 +1,63 @@
+/// Decides whether a failed attempt against one endpoint should fall
+/// through to the next base URL in `make_http_request_failover`, or be
+/// returned to the caller immediately.
+///
+/// A connection error (no status code at all, i.e. the request never got a
+/// response) always fails over. A response with a status code fails over
+/// only if it's explicitly listed in `failover_status_codes` -- in
+/// particular, a 4xx never fails over by default, since it means the
+/// request itself was rejected, not that the endpoint is unhealthy.
+pub(crate) fn should_failover(status_code: Option<u16>, failover_status_codes: &[u16]) -> bool {
+    match status_code {
+        None => true,
+        Some(code) => failover_status_codes.contains(&code),
+    }
+}
+
+/// Joins a base URL and a path into the endpoint actually requested, e.g.
+/// `join("https://eu.example.com/", "/v1/status")` ->
+/// `"https://eu.example.com/v1/status"`.
+pub(crate) fn join(base_url: &str, path: &str) -> String {
+    format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_error_always_fails_over() {
+        assert!(should_failover(None, &[]));
+    }
+
+    #[test]
+    fn test_listed_status_code_fails_over() {
+        assert!(should_failover(Some(503), &[502, 503, 504]));
+    }
+
+    #[test]
+    fn test_unlisted_status_code_does_not_fail_over() {
+        assert!(!should_failover(Some(500), &[502, 503, 504]));
+    }
+
+    #[test]
+    fn test_4xx_not_in_list_does_not_fail_over() {
+        assert!(!should_failover(Some(404), &[502, 503, 504]));
+    }
+
+    #[test]
+    fn test_join_normalizes_slashes() {
+        assert_eq!(
+            join("https://eu.example.com/", "/v1/status"),
+            "https://eu.example.com/v1/status"
+        );
+        assert_eq!(
+            join("https://eu.example.com", "v1/status"),
+            "https://eu.example.com/v1/status"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+/// A `Forwarded` header's `for`/`proto`/`host` params for one hop, built by
+/// `append_forwarded`. `for` and `proto` are required (RFC 7239 marks them
+/// optional in general, but a relay always knows the client address and
+/// protocol it received the request on); `host` is only sent when the
+/// caller supplies the original request's `Host`.
+pub(crate) struct ForwardedHop<'a> {
+    pub for_addr: &'a str,
+    pub proto: &'a str,
+    pub host: Option<&'a str>,
+}
+
+/// Appends `client_addr` to an existing `X-Forwarded-For` header value
+/// (comma-separated per convention), or starts a new one if `existing` is
+/// `None`. Never removes or reorders addresses already present, since each
+/// one records a hop closer to the original client than `client_addr` does.
+pub(crate) fn append_x_forwarded_for(existing: Option<&str>, client_addr: &str) -> String {
+    match existing {
+        Some(existing) if !existing.is_empty() => format!("{existing}, {client_addr}"),
+        _ => client_addr.to_string(),
+    }
+}
+
+/// Appends one hop to an existing `Forwarded` header value (comma-separated
+/// per RFC 7239 section 4), or starts a new one if `existing` is `None`.
+/// Quotes `for` (and `host`, if given) since an address can be an IPv6
+/// literal or carry a port, both of which need the quoted-string form.
+pub(crate) fn append_forwarded(existing: Option<&str>, hop: ForwardedHop) -> String {
+    let mut params = format!("for=\"{}\";proto={}", hop.for_addr, hop.proto);
+    if let Some(host) = hop.host {
+        params.push_str(&format!(";host=\"{host}\""));
+    }
+
+    match existing {
+        Some(existing) if !existing.is_empty() => format!("{existing}, {params}"),
+        _ => params,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_x_forwarded_for_no_existing_header() {
+        assert_eq!(append_x_forwarded_for(None, "203.0.113.5"), "203.0.113.5");
+    }
+
+    #[test]
+    fn test_append_x_forwarded_for_appends_in_order() {
+        assert_eq!(
+            append_x_forwarded_for(Some("203.0.113.1, 203.0.113.2"), "203.0.113.5"),
+            "203.0.113.1, 203.0.113.2, 203.0.113.5"
+        );
+    }
+
+    #[test]
+    fn test_append_forwarded_no_existing_header() {
+        assert_eq!(
+            append_forwarded(
+                None,
+                ForwardedHop {
+                    for_addr: "203.0.113.5",
+                    proto: "https",
+                    host: Some("example.com"),
+                }
+            ),
+            "for=\"203.0.113.5\";proto=https;host=\"example.com\""
+        );
+    }
+
+    #[test]
+    fn test_append_forwarded_omits_host_when_absent() {
+        assert_eq!(
+            append_forwarded(
+                None,
+                ForwardedHop {
+                    for_addr: "203.0.113.5",
+                    proto: "http",
+                    host: None,
+                }
+            ),
+            "for=\"203.0.113.5\";proto=http"
+        );
+    }
+
+    #[test]
+    fn test_append_forwarded_appends_as_new_element() {
+        assert_eq!(
+            append_forwarded(
+                Some("for=\"203.0.113.1\";proto=https"),
+                ForwardedHop {
+                    for_addr: "203.0.113.5",
+                    proto: "https",
+                    host: None,
+                }
+            ),
+            "for=\"203.0.113.1\";proto=https, for=\"203.0.113.5\";proto=https"
+        );
+    }
+
+    #[test]
+    fn test_append_forwarded_quotes_ipv6_for_addr() {
+        assert_eq!(
+            append_forwarded(
+                None,
+                ForwardedHop {
+                    for_addr: "[2001:db8::1]:8080",
+                    proto: "https",
+                    host: None,
+                }
+            ),
+            "for=\"[2001:db8::1]:8080\";proto=https"
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::api::grpc_web::GrpcWebFrame;
+
+/// The high bit of a gRPC-Web frame's flag byte marks it as a trailers
+/// frame instead of a data message (see the gRPC-Web wire format spec).
+const TRAILERS_FLAG: u8 = 0x80;
+
+/// Frames `message` for gRPC-Web: a 5-byte prefix (a compressed flag byte,
+/// then a 4-byte big-endian length) followed by the message bytes.
+pub(crate) fn frame_message(compressed: bool, message: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(5 + message.len());
+    framed.push(if compressed { 1 } else { 0 });
+    framed.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    framed.extend_from_slice(message);
+    framed
+}
+
+/// Parses every complete frame out of a `application/grpc-web` response
+/// body, in the order they appeared. A trailers frame's body is parsed as
+/// HTTP/1.1-style `name: value\r\n` header lines.
+///
+/// Returns an error if a frame's declared length runs past the end of
+/// `body`, or if a trailers frame's body isn't valid header text.
+pub(crate) fn parse_frames(mut body: &[u8]) -> Result<Vec<GrpcWebFrame>, String> {
+    let mut frames = Vec::new();
+
+    while !body.is_empty() {
+        if body.len() < 5 {
+            return Err("truncated gRPC-Web frame header".to_string());
+        }
+        let flags = body[0];
+        let len = u32::from_be_bytes([body[1], body[2], body[3], body[4]]) as usize;
+        let Some(rest) = body.get(5..) else {
+            return Err("truncated gRPC-Web frame header".to_string());
+        };
+        if rest.len() < len {
+            return Err(format!(
+                "truncated gRPC-Web frame body: declared {len} bytes, only {} available",
+                rest.len()
+            ));
+        }
+        let payload = &rest[..len];
+
+        frames.push(if flags & TRAILERS_FLAG != 0 {
+            GrpcWebFrame::Trailers(parse_trailers(payload)?)
+        } else {
+            GrpcWebFrame::Message(payload.to_vec())
+        });
+
+        body = &rest[len..];
+    }
+
+    Ok(frames)
+}
+
+fn parse_trailers(payload: &[u8]) -> Result<Vec<(String, String)>, String> {
+    let text = std::str::from_utf8(payload).map_err(|e| format!("invalid trailers text: {e}"))?;
+
+    text.split("\r\n")
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.split_once(':')
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .ok_or_else(|| format!("malformed trailer line: {line:?}"))
+        })
+        .collect()
+}
+
+/// Reads `grpc-status`/`grpc-message` out of a parsed trailers list.
+pub(crate) fn grpc_status(trailers: &[(String, String)]) -> (Option<String>, Option<String>) {
+    let find = |name: &str| {
+        trailers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.clone())
+    };
+    (find("grpc-status"), find("grpc-message"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_message_uncompressed() {
+        let framed = frame_message(false, b"hi");
+        assert_eq!(framed, vec![0, 0, 0, 0, 2, b'h', b'i']);
+    }
+
+    #[test]
+    fn test_frame_message_compressed() {
+        let framed = frame_message(true, b"hi");
+        assert_eq!(framed[0], 1);
+    }
+
+    #[test]
+    fn test_parse_frames_round_trips_message() {
+        let framed = frame_message(false, b"payload");
+        let frames = parse_frames(&framed).unwrap();
+        assert_eq!(frames, vec![GrpcWebFrame::Message(b"payload".to_vec())]);
+    }
+
+    #[test]
+    fn test_parse_frames_message_then_trailers() {
+        let mut body = frame_message(false, b"msg");
+        let trailers_payload = b"grpc-status: 0\r\ngrpc-message: OK\r\n";
+        let mut trailers_frame = vec![TRAILERS_FLAG];
+        trailers_frame.extend_from_slice(&(trailers_payload.len() as u32).to_be_bytes());
+        trailers_frame.extend_from_slice(trailers_payload);
+        body.extend_from_slice(&trailers_frame);
+
+        let frames = parse_frames(&body).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0], GrpcWebFrame::Message(b"msg".to_vec()));
+        match &frames[1] {
+            GrpcWebFrame::Trailers(trailers) => {
+                assert_eq!(grpc_status(trailers), (Some("0".to_string()), Some("OK".to_string())));
+            }
+            _ => panic!("expected trailers frame"),
+        }
+    }
+
+    #[test]
+    fn test_parse_frames_truncated_body_errors() {
+        let mut framed = frame_message(false, b"payload");
+        framed.truncate(framed.len() - 2);
+        assert!(parse_frames(&framed).is_err());
+    }
+
+    #[test]
+    fn test_parse_frames_empty_body_is_no_frames() {
+        assert_eq!(parse_frames(&[]).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,70 @@
+use futures_util::Stream;
+
+/// Wraps a byte stream in an incremental gzip decoder: `StreamReader` turns
+/// `stream` into an `AsyncRead`, `GzipDecoder` decompresses it, and
+/// `ReaderStream` turns the result back into a stream, yielding decompressed
+/// chunks as soon as the decoder has enough input to produce them rather
+/// than buffering until the body ends.
+pub(crate) fn decode_gzip_stream<S>(stream: S) -> impl Stream<Item = std::io::Result<bytes::Bytes>>
+where
+    S: Stream<Item = std::io::Result<bytes::Bytes>> + Send + 'static,
+{
+    let reader = tokio::io::BufReader::new(tokio_util::io::StreamReader::new(stream));
+    let decoder = async_compression::tokio::bufread::GzipDecoder::new(reader);
+    tokio_util::io::ReaderStream::new(decoder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+    use tokio::io::AsyncWriteExt;
+
+    #[tokio::test]
+    async fn test_decode_gzip_stream_yields_chunks_incrementally() {
+        let mut encoder = async_compression::tokio::write::GzipEncoder::new(Vec::new());
+        encoder.write_all(b"{\"line\":1}\n").await.unwrap();
+        encoder.flush().await.unwrap();
+        let first_compressed = encoder.get_ref().clone();
+
+        encoder.write_all(b"{\"line\":2}\n").await.unwrap();
+        encoder.shutdown().await.unwrap();
+        let second_compressed = encoder.get_ref()[first_compressed.len()..].to_vec();
+
+        let raw = futures_util::stream::iter(vec![
+            Ok(bytes::Bytes::from(first_compressed)),
+            Ok(bytes::Bytes::from(second_compressed)),
+        ]);
+        let mut decoded = Box::pin(decode_gzip_stream(raw));
+
+        // The flush between the two writes above means the compressed input
+        // arrives in two separate wire chunks, each independently
+        // decodable -- if the decoder buffered everything until EOF instead
+        // of decoding incrementally, this would come back as one chunk
+        // rather than two.
+        let first_chunk = decoded.next().await.unwrap().unwrap();
+        assert_eq!(&first_chunk[..], b"{\"line\":1}\n");
+
+        let second_chunk = decoded.next().await.unwrap().unwrap();
+        assert_eq!(&second_chunk[..], b"{\"line\":2}\n");
+
+        assert!(decoded.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_decode_gzip_stream_roundtrips_single_write() {
+        let mut encoder = async_compression::tokio::write::GzipEncoder::new(Vec::new());
+        encoder.write_all(b"hello world").await.unwrap();
+        encoder.shutdown().await.unwrap();
+        let compressed = encoder.into_inner();
+
+        let raw = futures_util::stream::iter(vec![Ok(bytes::Bytes::from(compressed))]);
+        let decoded: Vec<u8> = decode_gzip_stream(raw)
+            .map(|r| r.unwrap().to_vec())
+            .collect::<Vec<_>>()
+            .await
+            .concat();
+
+        assert_eq!(decoded, b"hello world");
+    }
+}
@@ -0,0 +1,199 @@
+/// One recorded request/response pair, ready to be serialized into a HAR
+/// 1.2 log entry by `to_har`. Built by `RequestClient`'s HAR recorder; see
+/// `RequestClient::enable_har_recording`.
+#[derive(Clone)]
+pub(crate) struct HarEntry {
+    pub started_date_time: String,
+    pub time_ms: f64,
+    pub method: String,
+    pub url: String,
+    pub request_headers: Vec<(String, String)>,
+    pub request_body_size: usize,
+    pub request_body_text: Option<String>,
+    pub status_code: u16,
+    pub response_headers: Vec<(String, String)>,
+
+    /// Taken from the response's `Content-Length` header, if present.
+    /// Actual response bytes aren't captured here: by the time an entry is
+    /// recorded, the response body hasn't been decoded yet, and consuming
+    /// it early would break the streaming request path.
+    pub response_body_size: Option<u64>,
+
+    /// The caller-supplied correlation id for the request that produced
+    /// this entry, if any -- see the `tag` parameter on `make_http_request`.
+    /// Never sent on the wire; recorded here as the entry's HAR `comment`.
+    pub tag: Option<String>,
+}
+
+/// Replaces the value of every header in `headers` whose name matches
+/// (case-insensitively) an entry in `redact` with `"REDACTED"`, so a
+/// recorded HAR entry can be shared for support without leaking secrets
+/// like `Authorization` or `Cookie`.
+pub(crate) fn redact_headers(
+    headers: &[(String, String)],
+    redact: &[String],
+) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let is_redacted = redact.iter().any(|r| r.eq_ignore_ascii_case(name));
+            if is_redacted {
+                (name.clone(), "REDACTED".to_string())
+            } else {
+                (name.clone(), value.clone())
+            }
+        })
+        .collect()
+}
+
+fn headers_to_json(headers: &[(String, String)]) -> serde_json::Value {
+    serde_json::Value::Array(
+        headers
+            .iter()
+            .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+            .collect(),
+    )
+}
+
+/// Serializes `entries` into a HAR 1.2 log document, as produced by browser
+/// devtools "Save all as HAR" and consumed by most HAR viewers. See
+/// `RequestClient::export_har`.
+pub(crate) fn to_har(entries: &[HarEntry]) -> String {
+    let entries: Vec<serde_json::Value> = entries
+        .iter()
+        .map(|entry| {
+            let mut request = serde_json::json!({
+                "method": entry.method,
+                "url": entry.url,
+                "httpVersion": "HTTP/1.1",
+                "cookies": [],
+                "headers": headers_to_json(&entry.request_headers),
+                "queryString": [],
+                "headersSize": -1,
+                "bodySize": entry.request_body_size,
+            });
+            if let Some(text) = &entry.request_body_text {
+                request["postData"] = serde_json::json!({
+                    "mimeType": "",
+                    "text": text,
+                });
+            }
+
+            let mut entry_json = serde_json::json!({
+                "startedDateTime": entry.started_date_time,
+                "time": entry.time_ms,
+                "request": request,
+                "response": {
+                    "status": entry.status_code,
+                    "statusText": "",
+                    "httpVersion": "HTTP/1.1",
+                    "cookies": [],
+                    "headers": headers_to_json(&entry.response_headers),
+                    "content": {
+                        "size": entry.response_body_size.unwrap_or(0),
+                        "mimeType": "",
+                    },
+                    "redirectURL": "",
+                    "headersSize": -1,
+                    "bodySize": entry.response_body_size.map(|s| s as i64).unwrap_or(-1),
+                },
+                "cache": {},
+                "timings": {
+                    "send": 0,
+                    "wait": entry.time_ms,
+                    "receive": 0,
+                },
+            });
+            if let Some(tag) = &entry.tag {
+                entry_json["comment"] = serde_json::json!(tag);
+            }
+            entry_json
+        })
+        .collect();
+
+    let har = serde_json::json!({
+        "log": {
+            "version": "1.2",
+            "creator": { "name": "rhttp", "version": env!("CARGO_PKG_VERSION") },
+            "entries": entries,
+        }
+    });
+
+    har.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_entry() -> HarEntry {
+        HarEntry {
+            started_date_time: "2024-01-01T00:00:00Z".to_string(),
+            time_ms: 12.5,
+            method: "POST".to_string(),
+            url: "https://example.com/login".to_string(),
+            request_headers: vec![("Authorization".to_string(), "Bearer secret".to_string())],
+            request_body_size: 9,
+            request_body_text: Some("user=bob".to_string()),
+            status_code: 200,
+            response_headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            response_body_size: Some(42),
+            tag: None,
+        }
+    }
+
+    #[test]
+    fn test_redact_headers_matches_case_insensitively() {
+        let headers = vec![("authorization".to_string(), "Bearer secret".to_string())];
+        let redacted = redact_headers(&headers, &["Authorization".to_string()]);
+        assert_eq!(redacted[0].1, "REDACTED");
+    }
+
+    #[test]
+    fn test_redact_headers_leaves_others_untouched() {
+        let headers = vec![("Content-Type".to_string(), "application/json".to_string())];
+        let redacted = redact_headers(&headers, &["Authorization".to_string()]);
+        assert_eq!(redacted[0].1, "application/json");
+    }
+
+    #[test]
+    fn test_to_har_structure() {
+        let json = to_har(&[sample_entry()]);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["log"]["version"], "1.2");
+        let entry = &value["log"]["entries"][0];
+        assert_eq!(entry["request"]["method"], "POST");
+        assert_eq!(entry["request"]["url"], "https://example.com/login");
+        assert_eq!(entry["request"]["postData"]["text"], "user=bob");
+        assert_eq!(entry["response"]["status"], 200);
+        assert_eq!(entry["response"]["content"]["size"], 42);
+    }
+
+    #[test]
+    fn test_to_har_omits_post_data_when_not_captured() {
+        let mut entry = sample_entry();
+        entry.request_body_text = None;
+        let json = to_har(&[entry]);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value["log"]["entries"][0]["request"]
+            .get("postData")
+            .is_none());
+    }
+
+    #[test]
+    fn test_to_har_includes_tag_as_comment() {
+        let mut entry = sample_entry();
+        entry.tag = Some("checkout-flow".to_string());
+        let json = to_har(&[entry]);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["log"]["entries"][0]["comment"], "checkout-flow");
+    }
+
+    #[test]
+    fn test_to_har_omits_comment_when_no_tag() {
+        let json = to_har(&[sample_entry()]);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value["log"]["entries"][0].get("comment").is_none());
+    }
+}
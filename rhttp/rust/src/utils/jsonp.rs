@@ -0,0 +1,134 @@
+/// Strips the callback wrapper from a JSONP response body
+/// (`callback({"a":1});`), returning the inner JSON text. See
+/// `HttpExpectBody::Jsonp`.
+///
+/// If `expected_callback` is given, the wrapper's callback name must match
+/// it exactly; otherwise the leading identifier is used, whatever it is.
+/// Leading/trailing whitespace and any trailing semicolons after the
+/// closing parenthesis are ignored. Returns an error if `body` doesn't
+/// parse as `identifier(...)` -- including unbalanced parentheses or
+/// trailing garbage after the closing one -- or if the callback name
+/// doesn't match `expected_callback`.
+pub(crate) fn unwrap(body: &str, expected_callback: Option<&str>) -> Result<String, String> {
+    let trimmed = body.trim();
+
+    let open = trimmed
+        .find('(')
+        .ok_or_else(|| "not a JSONP response: no callback invocation found".to_string())?;
+    let name = trimmed[..open].trim();
+
+    if name.is_empty() || !name.chars().all(is_identifier_char) {
+        return Err(format!(
+            "not a JSONP response: invalid callback name {name:?}"
+        ));
+    }
+
+    if let Some(expected) = expected_callback {
+        if name != expected {
+            return Err(format!(
+                "JSONP callback name {name:?} doesn't match expected callback {expected:?}"
+            ));
+        }
+    }
+
+    let close = matching_paren(&trimmed[open..])
+        .ok_or_else(|| "not a JSONP response: unbalanced parentheses".to_string())?
+        + open;
+
+    let trailing = trimmed[close + 1..].trim_end_matches(|c: char| c.is_whitespace() || c == ';');
+    if !trailing.is_empty() {
+        return Err(format!(
+            "not a JSONP response: unexpected trailing content {trailing:?}"
+        ));
+    }
+
+    Ok(trimmed[open + 1..close].to_string())
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$' || c == '.'
+}
+
+/// Given a string starting with `(`, finds the index of its matching `)`,
+/// accounting for nested parentheses in between.
+fn matching_paren(s: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unwraps_simple_callback() {
+        assert_eq!(
+            unwrap(r#"cb({"a":1});"#, None),
+            Ok(r#"{"a":1}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_unwraps_with_expected_callback_name() {
+        assert_eq!(
+            unwrap(r#"cb({"a":1});"#, Some("cb")),
+            Ok(r#"{"a":1}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_errors_on_callback_name_mismatch() {
+        assert!(unwrap(r#"cb({"a":1});"#, Some("other")).is_err());
+    }
+
+    #[test]
+    fn test_ignores_surrounding_whitespace() {
+        assert_eq!(
+            unwrap("  cb ( {\"a\":1} )  ;  ", None),
+            Ok(r#" {"a":1} "#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_strips_multiple_trailing_semicolons() {
+        assert_eq!(
+            unwrap(r#"cb({"a":1});;;"#, None),
+            Ok(r#"{"a":1}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_handles_nested_parentheses_in_payload() {
+        assert_eq!(
+            unwrap(r#"cb({"a":[1,(2)]});"#, None),
+            Ok(r#"{"a":[1,(2)]}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_errors_on_non_jsonp_body() {
+        assert!(unwrap(r#"{"a":1}"#, None).is_err());
+    }
+
+    #[test]
+    fn test_errors_on_unbalanced_parentheses() {
+        assert!(unwrap(r#"cb({"a":1}"#, None).is_err());
+    }
+
+    #[test]
+    fn test_errors_on_trailing_garbage() {
+        assert!(unwrap(r#"cb({"a":1})garbage"#, None).is_err());
+    }
+}
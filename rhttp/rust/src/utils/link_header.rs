@@ -0,0 +1,143 @@
+use reqwest::header::HeaderMap;
+
+/// One link in a `Link` header (RFC 5988), e.g.
+/// `<https://api.example.com/p?page=2>; rel="next"`.
+pub(crate) struct LinkHeaderEntry {
+    pub url: String,
+    pub params: Vec<(String, String)>,
+}
+
+/// Parses every `Link` header on a response (there can be more than one
+/// instance) into their entries, in header order.
+pub(crate) fn parse_all(headers: &HeaderMap) -> Vec<LinkHeaderEntry> {
+    headers
+        .get_all(reqwest::header::LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(parse)
+        .collect()
+}
+
+/// Parses one `Link` header value into its entries.
+pub(crate) fn parse(header_value: &str) -> Vec<LinkHeaderEntry> {
+    split_top_level(header_value, ',')
+        .filter_map(|entry| parse_entry(entry.trim()))
+        .collect()
+}
+
+/// Finds the URL of the first entry whose `rel` param matches `rel` (`rel` is
+/// case-sensitive, per RFC 5988 -- servers are expected to send the lowercase
+/// registered relation names).
+pub(crate) fn find_rel<'a>(entries: &'a [LinkHeaderEntry], rel: &str) -> Option<&'a str> {
+    entries
+        .iter()
+        .find(|entry| entry.params.iter().any(|(k, v)| k == "rel" && v == rel))
+        .map(|entry| entry.url.as_str())
+}
+
+fn parse_entry(entry: &str) -> Option<LinkHeaderEntry> {
+    let url = entry.strip_prefix('<')?;
+    let (url, rest) = url.split_once('>')?;
+
+    let params = split_top_level(rest, ';')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .filter_map(|param| {
+            param
+                .split_once('=')
+                .map(|(k, v)| (k.trim().to_string(), v.trim().trim_matches('"').to_string()))
+        })
+        .collect();
+
+    Some(LinkHeaderEntry {
+        url: url.to_string(),
+        params,
+    })
+}
+
+/// Splits `input` on `delim`, ignoring occurrences inside double quotes or
+/// inside a `<...>` URL reference (a URL is allowed to contain a literal
+/// comma or semicolon).
+fn split_top_level(input: &str, delim: char) -> impl Iterator<Item = &str> {
+    let mut result = Vec::new();
+    let mut in_quotes = false;
+    let mut in_url = false;
+    let mut start = 0;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '<' if !in_quotes => in_url = true,
+            '>' if !in_quotes => in_url = false,
+            c if c == delim && !in_quotes && !in_url => {
+                result.push(&input[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    result.push(&input[start..]);
+
+    result.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_next_link() {
+        let entries = parse("<https://api.example.com/p?page=2>; rel=\"next\"");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            find_rel(&entries, "next"),
+            Some("https://api.example.com/p?page=2")
+        );
+    }
+
+    #[test]
+    fn test_parse_multiple_links() {
+        let entries = parse(
+            "<https://api.example.com/p?page=1>; rel=\"prev\", <https://api.example.com/p?page=3>; rel=\"next\"",
+        );
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            find_rel(&entries, "prev"),
+            Some("https://api.example.com/p?page=1")
+        );
+        assert_eq!(
+            find_rel(&entries, "next"),
+            Some("https://api.example.com/p?page=3")
+        );
+    }
+
+    #[test]
+    fn test_parse_extra_params_are_ignored() {
+        let entries =
+            parse("<https://api.example.com/p?page=2>; rel=\"next\"; title=\"Next page\"");
+        assert_eq!(
+            find_rel(&entries, "next"),
+            Some("https://api.example.com/p?page=2")
+        );
+    }
+
+    #[test]
+    fn test_parse_url_containing_comma_and_semicolon() {
+        let entries = parse("<https://api.example.com/p?a=1,2;3>; rel=\"next\"");
+        assert_eq!(
+            find_rel(&entries, "next"),
+            Some("https://api.example.com/p?a=1,2;3")
+        );
+    }
+
+    #[test]
+    fn test_find_rel_absent_returns_none() {
+        let entries = parse("<https://api.example.com/p?page=1>; rel=\"prev\"");
+        assert_eq!(find_rel(&entries, "next"), None);
+    }
+
+    #[test]
+    fn test_parse_empty_header_is_no_entries() {
+        assert!(parse("").is_empty());
+    }
+}
@@ -1 +1,26 @@
+pub(crate) mod accept;
+pub(crate) mod access_control;
+pub(crate) mod alt_svc;
+pub(crate) mod checksum_trailer;
+pub(crate) mod client_snapshot;
+pub(crate) mod content_disposition;
+pub(crate) mod content_hash;
+pub(crate) mod decompression_guard;
+pub(crate) mod failover;
+pub(crate) mod forwarded;
+pub(crate) mod grpc_web;
+pub(crate) mod gzip_stream;
+pub(crate) mod har;
+pub(crate) mod jsonp;
+pub(crate) mod link_header;
+pub(crate) mod multipart_stream;
+pub(crate) mod ndjson;
+pub(crate) mod patch;
+pub(crate) mod query;
+pub(crate) mod rate_limiter;
+pub(crate) mod referer;
+pub(crate) mod smuggling;
 pub(crate) mod socket_addr;
+pub(crate) mod srv;
+pub(crate) mod trace_context;
+pub(crate) mod url;
@@ -0,0 +1,196 @@
+use crate::api::http::MultipartPart;
+
+/// Extracts the `boundary` parameter from a `multipart/...` `Content-Type`
+/// header value, e.g. `multipart/mixed; boundary=abc123` or
+/// `multipart/x-mixed-replace;boundary="abc 123"`. Returns `None` if no
+/// `boundary` parameter is present.
+pub(crate) fn parse_boundary(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.trim().split_once('=')?;
+        if !key.eq_ignore_ascii_case("boundary") {
+            return None;
+        }
+        Some(value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Incrementally parses a `multipart/mixed` or `multipart/x-mixed-replace`
+/// response body by boundary, delivering each part's headers and body as
+/// soon as its closing boundary line has fully arrived.
+///
+/// A part's own body is buffered whole between its header block and its
+/// closing boundary rather than streamed further chunk by chunk -- unlike a
+/// single-value delimiter (e.g. NDJSON's `\n`, see `ndjson::drain_ndjson_lines`),
+/// a multipart boundary line can itself span an arbitrary number of wire
+/// chunks, so there's no way to know a part's body has ended until the full
+/// marker has been seen. This is fine for MJPEG-style frames and typical
+/// `multipart/mixed` parts, but a single part with a truly huge body is
+/// still held in memory until its boundary shows up.
+pub(crate) struct MultipartParser {
+    buffer: Vec<u8>,
+    marker: Vec<u8>,
+    /// Whether the first boundary line (which opens the first part, with no
+    /// part preceding it to close) has been consumed yet.
+    started: bool,
+    finished: bool,
+}
+
+impl MultipartParser {
+    pub(crate) fn new(boundary: &str) -> Self {
+        Self {
+            buffer: Vec::new(),
+            marker: format!("--{boundary}").into_bytes(),
+            started: false,
+            finished: false,
+        }
+    }
+
+    pub(crate) fn finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Appends `chunk` and returns every part completed so far.
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Vec<MultipartPart> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut parts = Vec::new();
+        while !self.finished {
+            if !self.started {
+                // Nothing has been delivered yet -- skip past the preamble
+                // (if any) up to and including the first boundary line,
+                // which has no part before it to close.
+                let Some(start) = find(&self.buffer, &self.marker) else {
+                    break;
+                };
+                let after_marker = start + self.marker.len();
+                if self.buffer.len() < after_marker + 2 {
+                    break;
+                }
+                if &self.buffer[after_marker..after_marker + 2] == b"--" {
+                    self.finished = true;
+                    self.buffer.clear();
+                    break;
+                }
+                let Some(line_end) = find(&self.buffer[after_marker..], b"\r\n") else {
+                    break;
+                };
+                self.buffer.drain(..after_marker + line_end + 2);
+                self.started = true;
+                continue;
+            }
+
+            let Some(end) = find(&self.buffer, &self.marker) else {
+                break;
+            };
+            // The CRLF right before the boundary line belongs to the
+            // delimiter, not the part's body.
+            let body_end = end.saturating_sub(2);
+            let after_marker = end + self.marker.len();
+            if self.buffer.len() < after_marker + 2 {
+                break;
+            }
+            let is_final = &self.buffer[after_marker..after_marker + 2] == b"--";
+
+            let part_bytes: Vec<u8> = self.buffer.drain(..body_end).collect();
+            parts.push(to_multipart_part(&part_bytes));
+            // Drop the trailing CRLF plus the boundary line itself.
+            let remaining_marker_len = self.buffer.len().min(end - body_end + self.marker.len());
+            self.buffer.drain(..remaining_marker_len);
+
+            if is_final {
+                self.finished = true;
+                self.buffer.clear();
+                break;
+            }
+
+            let Some(line_end) = find(&self.buffer, b"\r\n") else {
+                break;
+            };
+            self.buffer.drain(..line_end + 2);
+        }
+
+        parts
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn to_multipart_part(bytes: &[u8]) -> MultipartPart {
+    let (header_block, body) = match find(bytes, b"\r\n\r\n") {
+        Some(pos) => (&bytes[..pos], &bytes[pos + 4..]),
+        None => (bytes, &[][..]),
+    };
+
+    let headers = String::from_utf8_lossy(header_block)
+        .split("\r\n")
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect();
+
+    MultipartPart {
+        headers,
+        body: body.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_boundary_unquoted() {
+        assert_eq!(
+            parse_boundary("multipart/mixed; boundary=abc123"),
+            Some("abc123".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_boundary_quoted() {
+        assert_eq!(
+            parse_boundary("multipart/x-mixed-replace;boundary=\"a b c\""),
+            Some("a b c".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_boundary_missing() {
+        assert_eq!(parse_boundary("multipart/mixed"), None);
+    }
+
+    #[test]
+    fn test_feed_parses_two_parts_in_one_chunk() {
+        let body = b"--frame\r\nContent-Type: text/plain\r\n\r\npart one\r\n--frame\r\nContent-Type: text/plain\r\n\r\npart two\r\n--frame--\r\n";
+        let mut parser = MultipartParser::new("frame");
+        let parts = parser.feed(body);
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].body, b"part one");
+        assert_eq!(
+            parts[0].headers,
+            vec![("Content-Type".to_string(), "text/plain".to_string())]
+        );
+        assert_eq!(parts[1].body, b"part two");
+        assert!(parser.finished());
+    }
+
+    #[test]
+    fn test_feed_handles_boundary_split_across_chunks() {
+        let full = b"--frame\r\nContent-Type: text/plain\r\n\r\npart one\r\n--frame--\r\n";
+        let mut parser = MultipartParser::new("frame");
+
+        let split_at = full.len() - 5;
+        let mut parts = parser.feed(&full[..split_at]);
+        assert!(parts.is_empty());
+        assert!(!parser.finished());
+
+        parts.extend(parser.feed(&full[split_at..]));
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].body, b"part one");
+        assert!(parser.finished());
+    }
+}
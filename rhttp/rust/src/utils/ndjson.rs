@@ -0,0 +1,93 @@
+use crate::api::http::NdjsonLine;
+
+/// Appends `chunk` to `buffer`, splits off every complete (newline-terminated)
+/// line, and validates each as JSON, returning one `NdjsonLine` per complete
+/// line found. Incomplete trailing bytes stay in `buffer` for the next
+/// chunk. Empty lines (a bare `\n`, or the blank line before a trailing
+/// `\r\n`) are skipped, matching how NDJSON producers pad their output.
+pub(crate) fn drain_ndjson_lines(buffer: &mut Vec<u8>, chunk: &[u8]) -> Vec<NdjsonLine> {
+    buffer.extend_from_slice(chunk);
+
+    let mut lines = Vec::new();
+    while let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
+        let line: Vec<u8> = buffer.drain(..=pos).collect();
+        if let Some(line) = to_ndjson_line(&line[..line.len() - 1]) {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+/// Validates any bytes left in `buffer` once the stream has ended, as a
+/// final line if non-empty -- NDJSON producers aren't required to end with
+/// a trailing newline.
+pub(crate) fn finish_ndjson_buffer(buffer: &[u8]) -> Option<NdjsonLine> {
+    to_ndjson_line(buffer)
+}
+
+fn to_ndjson_line(line: &[u8]) -> Option<NdjsonLine> {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    if line.is_empty() {
+        return None;
+    }
+
+    let text = String::from_utf8_lossy(line).into_owned();
+    match serde_json::from_str::<serde_json::Value>(&text) {
+        Ok(_) => Some(NdjsonLine::Json(text)),
+        Err(e) => Some(NdjsonLine::Malformed(text, e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drain_ndjson_lines_single_chunk() {
+        let mut buffer = Vec::new();
+        let lines = drain_ndjson_lines(&mut buffer, b"{\"a\":1}\n{\"a\":2}\n");
+        assert_eq!(lines.len(), 2);
+        assert!(matches!(&lines[0], NdjsonLine::Json(t) if t == "{\"a\":1}"));
+        assert!(matches!(&lines[1], NdjsonLine::Json(t) if t == "{\"a\":2}"));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_drain_ndjson_lines_buffers_partial_line() {
+        let mut buffer = Vec::new();
+        let lines = drain_ndjson_lines(&mut buffer, b"{\"a\":1}\n{\"a\":");
+        assert_eq!(lines.len(), 1);
+        assert_eq!(buffer, b"{\"a\":");
+
+        let lines = drain_ndjson_lines(&mut buffer, b"2}\n");
+        assert_eq!(lines.len(), 1);
+        assert!(matches!(&lines[0], NdjsonLine::Json(t) if t == "{\"a\":2}"));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_drain_ndjson_lines_skips_blank_lines() {
+        let mut buffer = Vec::new();
+        let lines = drain_ndjson_lines(&mut buffer, b"{\"a\":1}\r\n\n{\"a\":2}\n");
+        assert_eq!(lines.len(), 2);
+    }
+
+    #[test]
+    fn test_drain_ndjson_lines_reports_malformed_line() {
+        let mut buffer = Vec::new();
+        let lines = drain_ndjson_lines(&mut buffer, b"not json\n");
+        assert_eq!(lines.len(), 1);
+        assert!(matches!(&lines[0], NdjsonLine::Malformed(t, _) if t == "not json"));
+    }
+
+    #[test]
+    fn test_finish_ndjson_buffer_reads_trailing_line_without_newline() {
+        let line = finish_ndjson_buffer(b"{\"a\":3}");
+        assert!(matches!(line, Some(NdjsonLine::Json(t)) if t == "{\"a\":3}"));
+    }
+
+    #[test]
+    fn test_finish_ndjson_buffer_empty_is_none() {
+        assert!(finish_ndjson_buffer(b"").is_none());
+    }
+}
@@ -0,0 +1,81 @@
+/// The `Content-Type` for a JSON Merge Patch body (RFC 7396).
+pub(crate) const MERGE_PATCH_CONTENT_TYPE: &str = "application/merge-patch+json";
+
+/// The `Content-Type` for a JSON Patch body (RFC 6902).
+pub(crate) const JSON_PATCH_CONTENT_TYPE: &str = "application/json-patch+json";
+
+/// Validates that `body` is well-formed JSON Patch (RFC 6902): a JSON array
+/// of operation objects, each with at least an `op` and a `path` member.
+/// Doesn't validate `op`'s value against the fixed operation set (`add`,
+/// `remove`, `replace`, ...) -- a server rejecting an unknown op is a
+/// perfectly good error to surface, so this only catches structural
+/// mistakes that would fail before the body is ever sent.
+pub(crate) fn validate_json_patch(body: &str) -> Result<(), String> {
+    let value: serde_json::Value =
+        serde_json::from_str(body).map_err(|e| format!("invalid JSON: {e}"))?;
+
+    let Some(operations) = value.as_array() else {
+        return Err("a JSON Patch body must be a JSON array of operation objects".to_string());
+    };
+
+    for (i, operation) in operations.iter().enumerate() {
+        let Some(operation) = operation.as_object() else {
+            return Err(format!("operation {i} is not a JSON object"));
+        };
+        if !operation.contains_key("op") {
+            return Err(format!("operation {i} is missing the required 'op' member"));
+        }
+        if !operation.contains_key("path") {
+            return Err(format!(
+                "operation {i} is missing the required 'path' member"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_json_patch_accepts_valid_document() {
+        let body = r#"[{"op": "replace", "path": "/a", "value": 1}]"#;
+        assert!(validate_json_patch(body).is_ok());
+    }
+
+    #[test]
+    fn test_validate_json_patch_rejects_non_array() {
+        let body = r#"{"op": "replace", "path": "/a", "value": 1}"#;
+        assert!(validate_json_patch(body).is_err());
+    }
+
+    #[test]
+    fn test_validate_json_patch_rejects_invalid_json() {
+        assert!(validate_json_patch("not json").is_err());
+    }
+
+    #[test]
+    fn test_validate_json_patch_rejects_missing_op() {
+        let body = r#"[{"path": "/a", "value": 1}]"#;
+        assert!(validate_json_patch(body).is_err());
+    }
+
+    #[test]
+    fn test_validate_json_patch_rejects_missing_path() {
+        let body = r#"[{"op": "replace", "value": 1}]"#;
+        assert!(validate_json_patch(body).is_err());
+    }
+
+    #[test]
+    fn test_validate_json_patch_rejects_non_object_operation() {
+        let body = r#"["not an object"]"#;
+        assert!(validate_json_patch(body).is_err());
+    }
+
+    #[test]
+    fn test_validate_json_patch_accepts_empty_array() {
+        assert!(validate_json_patch("[]").is_ok());
+    }
+}
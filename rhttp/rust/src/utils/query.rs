@@ -0,0 +1,77 @@
+use reqwest::Url;
+
+use crate::api::http::QueryArrayEncoding;
+
+/// Appends `params` to `url`, percent-encoding each pair and grouping values
+/// that share a key according to `encoding`. Any query `url` already has is
+/// left untouched, so the result is a merge rather than a replacement.
+pub(crate) fn append_query(url: &mut Url, params: &[(String, String)], encoding: QueryArrayEncoding) {
+    match encoding {
+        QueryArrayEncoding::Repeat => {
+            let mut pairs = url.query_pairs_mut();
+            for (key, value) in params {
+                pairs.append_pair(key, value);
+            }
+        }
+        QueryArrayEncoding::Brackets => {
+            let mut pairs = url.query_pairs_mut();
+            for (key, value) in params {
+                pairs.append_pair(&format!("{key}[]"), value);
+            }
+        }
+        QueryArrayEncoding::Comma => {
+            let mut grouped: Vec<(&str, Vec<&str>)> = Vec::new();
+            for (key, value) in params {
+                match grouped.iter_mut().find(|(k, _)| k == key) {
+                    Some((_, values)) => values.push(value),
+                    None => grouped.push((key, vec![value])),
+                }
+            }
+
+            let mut pairs = url.query_pairs_mut();
+            for (key, values) in grouped {
+                pairs.append_pair(key, &values.join(","));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_append_query_repeat() {
+        let mut url = Url::parse("https://example.com/path").unwrap();
+        append_query(&mut url, &pairs(&[("a", "1"), ("a", "2")]), QueryArrayEncoding::Repeat);
+        assert_eq!(url.as_str(), "https://example.com/path?a=1&a=2");
+    }
+
+    #[test]
+    fn test_append_query_brackets() {
+        let mut url = Url::parse("https://example.com/path").unwrap();
+        append_query(&mut url, &pairs(&[("a", "1"), ("a", "2")]), QueryArrayEncoding::Brackets);
+        assert_eq!(url.as_str(), "https://example.com/path?a%5B%5D=1&a%5B%5D=2");
+    }
+
+    #[test]
+    fn test_append_query_comma() {
+        let mut url = Url::parse("https://example.com/path").unwrap();
+        append_query(&mut url, &pairs(&[("a", "1"), ("a", "2")]), QueryArrayEncoding::Comma);
+        assert_eq!(url.as_str(), "https://example.com/path?a=1%2C2");
+    }
+
+    #[test]
+    fn test_append_query_preserves_existing() {
+        let mut url = Url::parse("https://example.com/path?existing=1").unwrap();
+        append_query(&mut url, &pairs(&[("a", "1")]), QueryArrayEncoding::Repeat);
+        assert_eq!(url.as_str(), "https://example.com/path?existing=1&a=1");
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// A smooth (non-bursty) byte-rate limiter, shared by every body stream
+/// paced against it (one per client direction, see `BandwidthSettings`). Each
+/// `consume` call sleeps just long enough to keep the running average at
+/// `bytes_per_sec`, rather than allowing a burst followed by a stall. See
+/// `BandwidthSettings`.
+pub(crate) struct TokenBucket {
+    bytes_per_sec: u64,
+    /// Bytes already "spent" ahead of what `bytes_per_sec` would allow by
+    /// now. Decays continuously with elapsed time in `consume`.
+    debt_bytes: f64,
+    last_consume: Instant,
+}
+
+impl TokenBucket {
+    pub(crate) fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec,
+            debt_bytes: 0.0,
+            last_consume: Instant::now(),
+        }
+    }
+
+    /// Accounts for `bytes` just transferred, sleeping first if the bucket
+    /// is currently over rate. Equivalent to `consume_weighted(bytes, 1.0)`.
+    pub(crate) async fn consume(&mut self, bytes: usize) {
+        self.consume_weighted(bytes, 1.0).await;
+    }
+
+    /// Like `consume`, but scales `bytes`' effect on the bucket's debt by
+    /// `1.0 / weight` first, so a caller with a higher `weight` accrues
+    /// less debt (and so sleeps less) per byte than one with a lower
+    /// weight, when they're sharing the same bucket. Used to give a
+    /// per-request `BandwidthPriority` a proportional share of a client's
+    /// shared bandwidth cap; pass `1.0` for no adjustment.
+    pub(crate) async fn consume_weighted(&mut self, bytes: usize, weight: f64) {
+        if self.bytes_per_sec == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_consume).as_secs_f64();
+        self.last_consume = now;
+        self.debt_bytes = (self.debt_bytes - elapsed * self.bytes_per_sec as f64).max(0.0);
+        self.debt_bytes += bytes as f64 / weight;
+
+        let delay_secs = self.debt_bytes / self.bytes_per_sec as f64;
+        if delay_secs > 0.0 {
+            tokio::time::sleep(Duration::from_secs_f64(delay_secs)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_starts_with_no_debt() {
+        let bucket = TokenBucket::new(1000);
+        assert_eq!(bucket.debt_bytes, 0.0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_consume_small_amount_sleeps_only_its_share() {
+        let mut bucket = TokenBucket::new(1000);
+        let start = Instant::now();
+        bucket.consume(10).await;
+        assert_eq!(Instant::now().duration_since(start), Duration::from_millis(10));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_consume_over_rate_sleeps_proportionally() {
+        let mut bucket = TokenBucket::new(1000);
+        let start = Instant::now();
+        bucket.consume(5000).await;
+        assert_eq!(Instant::now().duration_since(start), Duration::from_secs(5));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_zero_rate_disables_throttling() {
+        let mut bucket = TokenBucket::new(0);
+        let start = Instant::now();
+        bucket.consume(1_000_000).await;
+        assert_eq!(Instant::now(), start);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_higher_weight_sleeps_proportionally_less() {
+        let mut high = TokenBucket::new(1000);
+        let mut low = TokenBucket::new(1000);
+        let start = Instant::now();
+
+        high.consume_weighted(5000, 2.0).await;
+        let high_elapsed = Instant::now().duration_since(start);
+
+        let start = Instant::now();
+        low.consume_weighted(5000, 0.5).await;
+        let low_elapsed = Instant::now().duration_since(start);
+
+        assert_eq!(high_elapsed, Duration::from_millis(2500));
+        assert_eq!(low_elapsed, Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,80 @@
+use reqwest::header::{self, HeaderMap};
+
+/// Checks `headers` for the two classic request/response-smuggling tells: a
+/// `Content-Length` sent more than once with disagreeing values, or
+/// `Content-Length` and `Transfer-Encoding` both present at all -- either
+/// lets a front-end proxy and this client disagree on where the body ends.
+/// Returns the offending detail as `Err` for the caller to wrap in
+/// `RhttpError::RhttpProtocolError`. See
+/// `ClientSettings::reject_ambiguous_content_length`.
+pub(crate) fn check_for_smuggling_signature(headers: &HeaderMap) -> Result<(), String> {
+    let lengths: Vec<&str> = headers
+        .get_all(header::CONTENT_LENGTH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .collect();
+
+    if lengths.iter().any(|l| *l != lengths[0]) {
+        return Err(format!(
+            "response has conflicting Content-Length headers: {}",
+            lengths.join(", ")
+        ));
+    }
+
+    if !lengths.is_empty() && headers.contains_key(header::TRANSFER_ENCODING) {
+        return Err("response has both Content-Length and Transfer-Encoding headers".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(
+                header::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                value.parse().unwrap(),
+            );
+        }
+        headers
+    }
+
+    #[test]
+    fn test_allows_single_content_length() {
+        let headers = headers(&[("content-length", "10")]);
+        assert!(check_for_smuggling_signature(&headers).is_ok());
+    }
+
+    #[test]
+    fn test_allows_no_content_length() {
+        assert!(check_for_smuggling_signature(&HeaderMap::new()).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_conflicting_content_length() {
+        let headers = headers(&[("content-length", "10"), ("content-length", "20")]);
+        assert!(check_for_smuggling_signature(&headers).is_err());
+    }
+
+    #[test]
+    fn test_allows_repeated_identical_content_length() {
+        let headers = headers(&[("content-length", "10"), ("content-length", "10")]);
+        assert!(check_for_smuggling_signature(&headers).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_content_length_with_transfer_encoding() {
+        let headers = headers(&[("content-length", "10"), ("transfer-encoding", "chunked")]);
+        assert!(check_for_smuggling_signature(&headers).is_err());
+    }
+
+    #[test]
+    fn test_allows_transfer_encoding_without_content_length() {
+        let headers = headers(&[("transfer-encoding", "chunked")]);
+        assert!(check_for_smuggling_signature(&headers).is_ok());
+    }
+}
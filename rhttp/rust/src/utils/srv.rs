@@ -0,0 +1,83 @@
+use crate::api::client::SrvRecord;
+
+/// Picks one record out of a SRV query's answer, following RFC 2782's
+/// priority/weight rules: records at the lowest `priority` are preferred,
+/// and `roll` (expected to be uniform in `[0, 1)`, e.g. from `rand::random`)
+/// selects between them proportionally to `weight`. See `SrvDnsSettings`.
+///
+/// `roll` is a parameter rather than sampled internally so the selection
+/// logic itself stays a pure, deterministically testable function.
+pub(crate) fn select_target(records: &[SrvRecord], roll: f64) -> Option<&SrvRecord> {
+    let lowest_priority = records.iter().map(|r| r.priority).min()?;
+    let candidates: Vec<&SrvRecord> = records
+        .iter()
+        .filter(|r| r.priority == lowest_priority)
+        .collect();
+
+    let total_weight: u32 = candidates.iter().map(|r| r.weight as u32).sum();
+    if total_weight == 0 {
+        return candidates.into_iter().next();
+    }
+
+    let mut threshold = (roll.clamp(0.0, 1.0) * total_weight as f64) as u32;
+    for candidate in &candidates {
+        if candidate.weight as u32 > threshold {
+            return Some(candidate);
+        }
+        threshold -= candidate.weight as u32;
+    }
+
+    candidates.into_iter().last()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(target: &str, port: u16, priority: u16, weight: u16) -> SrvRecord {
+        SrvRecord {
+            target: target.to_string(),
+            port,
+            priority,
+            weight,
+        }
+    }
+
+    #[test]
+    fn test_returns_none_for_empty_records() {
+        assert!(select_target(&[], 0.5).is_none());
+    }
+
+    #[test]
+    fn test_prefers_lowest_priority() {
+        let records = vec![record("b", 80, 10, 1), record("a", 80, 0, 1)];
+        assert_eq!(select_target(&records, 0.5).unwrap().target, "a");
+    }
+
+    #[test]
+    fn test_picks_only_candidate_at_lowest_priority() {
+        let records = vec![record("a", 80, 0, 1)];
+        assert_eq!(select_target(&records, 0.9).unwrap().target, "a");
+    }
+
+    #[test]
+    fn test_zero_weight_candidates_still_selectable() {
+        let records = vec![record("a", 80, 0, 0)];
+        assert_eq!(select_target(&records, 0.5).unwrap().target, "a");
+    }
+
+    #[test]
+    fn test_weighted_selection_favors_higher_weight() {
+        let records = vec![record("low", 80, 0, 1), record("high", 80, 0, 9)];
+        // First tenth of the roll range lands on "low", the rest on "high".
+        assert_eq!(select_target(&records, 0.05).unwrap().target, "low");
+        assert_eq!(select_target(&records, 0.5).unwrap().target, "high");
+        assert_eq!(select_target(&records, 0.99).unwrap().target, "high");
+    }
+
+    #[test]
+    fn test_roll_at_zero_picks_first_candidate_with_nonzero_weight() {
+        let records = vec![record("a", 80, 0, 5), record("b", 80, 0, 5)];
+        assert_eq!(select_target(&records, 0.0).unwrap().target, "a");
+    }
+}
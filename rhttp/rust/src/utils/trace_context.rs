@@ -0,0 +1,45 @@
+/// Formats a W3C Trace Context `traceparent` header value: the version
+/// byte `00`, then the 32-hex-character trace id, the 16-hex-character
+/// span id, and a 2-hex-character flags byte -- `01` if sampled, `00`
+/// otherwise. https://www.w3.org/TR/trace-context/#traceparent-header
+pub(crate) fn format_traceparent(trace_id: &str, span_id: &str, sampled: bool) -> String {
+    format!(
+        "00-{}-{}-{}",
+        trace_id.to_lowercase(),
+        span_id.to_lowercase(),
+        if sampled { "01" } else { "00" }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formats_sampled_traceparent() {
+        assert_eq!(
+            format_traceparent("4bf92f3577b34da6a3ce929d0e0e4736", "00f067aa0ba902b7", true),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+    }
+
+    #[test]
+    fn test_formats_unsampled_traceparent() {
+        assert_eq!(
+            format_traceparent(
+                "4bf92f3577b34da6a3ce929d0e0e4736",
+                "00f067aa0ba902b7",
+                false
+            ),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00"
+        );
+    }
+
+    #[test]
+    fn test_lowercases_hex_ids() {
+        assert_eq!(
+            format_traceparent("4BF92F3577B34DA6A3CE929D0E0E4736", "00F067AA0BA902B7", true),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+    }
+}